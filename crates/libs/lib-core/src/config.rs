@@ -1,6 +1,6 @@
 use std::{
     fs,
-    path::PathBuf,
+    path::{Path, PathBuf},
     process::{Command, Stdio},
     sync::OnceLock,
 };
@@ -37,9 +37,11 @@ pub struct CoreConfig {
 #[allow(non_snake_case)]
 pub struct Paths {
     BACKUP: PathBuf,
+    CACHE: PathBuf,
     DB: PathBuf,
     IMAGES: PathBuf,
     LOGS: PathBuf,
+    REGISTRY: PathBuf,
     THUMBNAILS: PathBuf,
     VIDEOS: PathBuf,
 }
@@ -116,13 +118,16 @@ impl Paths {
         root.push("Recipya2");
 
         let backup = root.join("Backup");
+        let cache = root.join("Cache");
         let db = root.join("Database");
         let images = root.join("Images");
         let logs = root.join("Logs");
+        let registry = root.join("websites.toml");
         let thumbnails = images.join("Thumbnails");
         let videos = root.join("Videos");
 
         fs::create_dir_all(&backup).unwrap();
+        fs::create_dir_all(&cache).unwrap();
         fs::create_dir_all(&db).unwrap();
         fs::create_dir_all(&logs).unwrap();
         fs::create_dir_all(&thumbnails).unwrap();
@@ -132,19 +137,38 @@ impl Paths {
         info!("{stars}");
         info!("File locations:");
         info!("\tBackups:  {}", backup.display());
+        info!("\tCache:    {}", cache.display());
         info!("\tDatabase: {}", db.display());
         info!("\tImages:   {}", images.display());
         info!("\tLogs:     {}", logs.display());
+        info!("\tWebsites: {}", registry.display());
         info!("\tVideos:   {}", videos.display());
         info!("{stars}");
 
         Ok(Paths {
             BACKUP: backup,
+            CACHE: cache,
             DB: db,
             IMAGES: images,
             LOGS: logs,
+            REGISTRY: registry,
             THUMBNAILS: thumbnails,
             VIDEOS: videos,
         })
     }
+
+    /// The directory of the HTTP response cache of the scraper.
+    pub fn cache(&self) -> &Path {
+        &self.CACHE
+    }
+
+    /// The file of the websites added to the supported ones, in TOML. It is optional.
+    pub fn registry(&self) -> &Path {
+        &self.REGISTRY
+    }
+
+    /// The directory of the videos of the recipes.
+    pub fn videos(&self) -> &Path {
+        &self.VIDEOS
+    }
 }
@@ -1,5 +1,7 @@
 pub mod config;
 pub mod ctx;
 pub mod model;
+pub mod scraper;
+pub mod video;
 
 pub use config::*;
@@ -23,6 +23,7 @@ pub enum Error {
     // Externals
     Diesel(String),
     FailConnection(String),
+    Scraper(String),
 }
 
 impl From<diesel_async::pooled_connection::bb8::RunError> for Error {
@@ -31,6 +32,12 @@ impl From<diesel_async::pooled_connection::bb8::RunError> for Error {
     }
 }
 
+impl From<lib_scraper::Error> for Error {
+    fn from(value: lib_scraper::Error) -> Self {
+        Error::Scraper(value.to_string())
+    }
+}
+
 impl From<diesel::result::Error> for Error {
     fn from(value: diesel::result::Error) -> Self {
         Error::Diesel(value.to_string())
@@ -29,8 +29,10 @@ pub use self::error::{Error, Result};
 mod error;
 pub mod store;
 
+pub mod recipe;
 pub(in crate::model) mod schema;
 pub mod user;
+pub mod website;
 
 #[derive(Clone)]
 pub struct ModelManager {
@@ -0,0 +1,115 @@
+use diesel::{ExpressionMethods, NullableExpressionMethods, QueryDsl};
+use diesel_async::RunQueryDsl;
+
+use crate::{
+    model::{schema, Result},
+    {ctx::Ctx, model::ModelManager},
+};
+
+/// Recipe backend model controller.
+pub struct RecipeBmc;
+
+impl RecipeBmc {
+    /// The URLs the recipes of the user were imported from, e.g. for the known URLs of a
+    /// [`CrawlConfig`](lib_scraper::CrawlConfig) so that a crawl only finds new recipes.
+    pub async fn urls(ctx: &Ctx, mm: &ModelManager) -> Result<Vec<String>> {
+        Ok(schema::recipes::dsl::recipes
+            .filter(schema::recipes::user_id.eq(ctx.user_id()))
+            .filter(schema::recipes::url.is_not_null())
+            .select(schema::recipes::url.assume_not_null())
+            .load(&mut *mm.connection().await?)
+            .await?)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    pub type Result<T> = core::result::Result<T, Error>;
+    pub type Error = Box<dyn std::error::Error>;
+
+    use super::*;
+    use crate::model::{
+        store::{test_db::TestDb, Pool},
+        user::{UserBmc, UserForCreate},
+    };
+    use futures::FutureExt;
+
+    #[tokio::test]
+    async fn test_urls_ok() -> Result<()> {
+        let db = TestDb::new().await;
+        db.run_test(|| {
+            let db = db.pool.clone();
+
+            async move {
+                let (mm, ctx) = setup(db);
+                let fx_user_id = add_user(&ctx, &mm, "example@test.com").await;
+                let fx_other_user_id = add_user(&ctx, &mm, "other@test.com").await;
+                add_recipe(&mm, fx_user_id, Some("https://soupes.example/pea-soup/")).await;
+                add_recipe(&mm, fx_user_id, None).await;
+                add_recipe(
+                    &mm,
+                    fx_other_user_id,
+                    Some("https://soupes.example/leek-soup/"),
+                )
+                .await;
+
+                let urls = RecipeBmc::urls(&Ctx::new(fx_user_id).unwrap(), &mm)
+                    .await
+                    .unwrap();
+
+                assert_eq!(urls, vec!["https://soupes.example/pea-soup/".to_string()]);
+            }
+            .boxed()
+        })
+        .await;
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn test_urls_none_ok() -> Result<()> {
+        let db = TestDb::new().await;
+        db.run_test(|| {
+            let db = db.pool.clone();
+
+            async move {
+                let (mm, ctx) = setup(db);
+
+                let urls = RecipeBmc::urls(&ctx, &mm).await.unwrap();
+
+                assert!(urls.is_empty());
+            }
+            .boxed()
+        })
+        .await;
+        Ok(())
+    }
+
+    fn setup(db: Pool) -> (ModelManager, Ctx) {
+        (ModelManager { db, email: None }, Ctx::root_ctx())
+    }
+
+    async fn add_user(ctx: &Ctx, mm: &ModelManager, email: impl Into<String>) -> i64 {
+        UserBmc::create(
+            ctx,
+            mm,
+            UserForCreate {
+                email: email.into(),
+                password_clear: "12345678".to_string(),
+            },
+        )
+        .await
+        .unwrap()
+    }
+
+    async fn add_recipe(mm: &ModelManager, user_id: i64, url: Option<&str>) {
+        diesel::insert_into(schema::recipes::table)
+            .values((
+                schema::recipes::name.eq("Soup"),
+                schema::recipes::url.eq(url),
+                schema::recipes::user_id.eq(user_id),
+            ))
+            .execute(&mut *mm.connection().await.unwrap())
+            .await
+            .unwrap();
+    }
+}
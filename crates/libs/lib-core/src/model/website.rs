@@ -0,0 +1,100 @@
+use diesel::{ExpressionMethods, Insertable, QueryDsl, Queryable, Selectable, SelectableHelper};
+use diesel_async::RunQueryDsl;
+use lib_scraper::Registry;
+use serde::Serialize;
+
+use crate::{
+    model::{schema, Result},
+    {ctx::Ctx, model::ModelManager},
+};
+
+#[derive(Clone, Debug, Queryable, Selectable, Serialize)]
+#[diesel(table_name = schema::websites)]
+#[diesel(check_for_backend(diesel::pg::Pg))]
+pub struct Website {
+    pub id: i64,
+    pub host: String,
+    pub url: String,
+}
+
+#[derive(Insertable)]
+#[diesel(table_name = schema::websites)]
+pub(in crate::model) struct WebsiteForInsert {
+    pub(in crate::model) host: String,
+    pub(in crate::model) url: String,
+}
+
+/// Website backend model controller.
+pub struct WebsiteBmc;
+
+impl WebsiteBmc {
+    pub async fn list(_ctx: &Ctx, mm: &ModelManager) -> Result<Vec<Website>> {
+        Ok(schema::websites::dsl::websites
+            .select(Website::as_select())
+            .order(schema::websites::host.asc())
+            .load(&mut *mm.connection().await?)
+            .await?)
+    }
+
+    /// Adds the websites of the scraper's registry, built-in ones included, that are not in
+    /// the table yet. Returns the number of websites added.
+    pub async fn sync(_ctx: &Ctx, mm: &ModelManager, registry: &Registry) -> Result<usize> {
+        let websites: Vec<WebsiteForInsert> = registry
+            .websites()
+            .into_iter()
+            .map(|(host, url)| WebsiteForInsert { host, url })
+            .collect();
+
+        let res = diesel::insert_into(schema::websites::table)
+            .values(&websites)
+            .on_conflict_do_nothing()
+            .execute(&mut *mm.connection().await?)
+            .await?;
+
+        Ok(res)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    pub type Result<T> = core::result::Result<T, Error>;
+    pub type Error = Box<dyn std::error::Error>;
+
+    use super::*;
+    use crate::model::store::{test_db::TestDb, Pool};
+    use futures::FutureExt;
+
+    #[tokio::test]
+    async fn test_sync_ok() -> Result<()> {
+        let db = TestDb::new().await;
+        db.run_test(|| {
+            let db = db.pool.clone();
+
+            async move {
+                let (mm, ctx) = setup(db);
+                let fx_registry = Registry::from_json(
+                    r#"{"websites": [{"host": "family-recipes.example", "url": "https://family-recipes.example/recipes"}]}"#,
+                )
+                .unwrap();
+
+                let added = WebsiteBmc::sync(&ctx, &mm, &fx_registry).await.unwrap();
+                let added_again = WebsiteBmc::sync(&ctx, &mm, &fx_registry).await.unwrap();
+
+                assert!(added > 0);
+                assert_eq!(added_again, 0);
+                let websites = WebsiteBmc::list(&ctx, &mm).await.unwrap();
+                assert!(websites.iter().any(|website| {
+                    website.host == "family-recipes.example"
+                        && website.url == "https://family-recipes.example/recipes"
+                }));
+            }
+            .boxed()
+        })
+        .await;
+        Ok(())
+    }
+
+    fn setup(db: Pool) -> (ModelManager, Ctx) {
+        (ModelManager { db, email: None }, Ctx::root_ctx())
+    }
+}
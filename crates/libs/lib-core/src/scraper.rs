@@ -0,0 +1,36 @@
+//! Setup of the scraper of the recipes.
+
+use lib_scraper::{CacheConfig, Registry, ScraperConfig};
+use tracing::info;
+
+use crate::{
+    config::config,
+    ctx::Ctx,
+    model::{website::WebsiteBmc, ModelManager, Result},
+};
+
+/// Builds the scraper of the app and adds the websites of its registry to the websites table.
+///
+/// The registry is read from [`Paths::registry`](crate::config::Paths::registry) when the file
+/// exists. The pages fetched are kept in [`Paths::cache`](crate::config::Paths::cache). Must be
+/// called at startup, before any recipe is scraped.
+pub async fn init_scraper(mm: &ModelManager) -> Result<()> {
+    let path = config().PATHS.registry();
+    let registry = if path.exists() {
+        Registry::load(path)?
+    } else {
+        Registry::default()
+    };
+
+    let added = WebsiteBmc::sync(&Ctx::root_ctx(), mm, &registry).await?;
+    if added > 0 {
+        info!("Added {added} websites to the supported websites");
+    }
+
+    lib_scraper::init(
+        ScraperConfig::default()
+            .cache(CacheConfig::new(config().PATHS.cache()))
+            .registry(registry),
+    )?;
+    Ok(())
+}
@@ -0,0 +1,28 @@
+//! Storage of the videos of recipes.
+
+use lib_scraper::video::RecipeVideo;
+use tracing::warn;
+use uuid::Uuid;
+
+use crate::config::config;
+
+/// Downloads the video of a recipe into the videos directory when ffmpeg is installed.
+///
+/// Returns the ID of the video, which names its file along with the extension of the video,
+/// or `None` when the video was not downloaded. Videos hosted on YouTube or Vimeo are only embedded.
+pub async fn download_video(video: &RecipeVideo) -> Option<Uuid> {
+    if !config().IS_FFMPEG_INSTALLED {
+        return None;
+    }
+    let extension = video.file_extension()?;
+
+    let id = Uuid::new_v4();
+    let path = config().PATHS.videos().join(format!("{id}.{extension}"));
+    match video.download(&path).await {
+        Ok(_) => Some(id),
+        Err(ex) => {
+            warn!("Could not download the video {:?}: {ex}", video.content_url);
+            None
+        }
+    }
+}
@@ -0,0 +1,255 @@
+//! An on-disk cache of the pages fetched by the scraper.
+//!
+//! Every page is stored in its own file, named after the hash of its URL. A page younger than
+//! the TTL is served from the disk. An older page is revalidated with its `ETag` and
+//! `Last-Modified` validators, so the website only sends it again when it changed.
+
+use serde::{Deserialize, Serialize};
+use std::{
+    fs,
+    path::{Path, PathBuf},
+    time::{Duration, SystemTime, UNIX_EPOCH},
+};
+
+use crate::{websites::Website, Error, HttpClient, Result};
+
+/// The settings of the response cache of a [`Scraper`](crate::Scraper).
+///
+/// ```ignore
+/// let scraper = ScraperConfig::default()
+///     .cache(CacheConfig::new(paths.cache()).ttl(Duration::from_secs(3600)))
+///     .build()?;
+/// ```
+#[derive(Clone, Debug)]
+pub struct CacheConfig {
+    pub(crate) dir: PathBuf,
+    pub(crate) ttl: Duration,
+    pub(crate) max_size: u64,
+    pub(crate) offline: bool,
+}
+
+impl CacheConfig {
+    /// Stores the pages in the directory, which is created if needed.
+    pub fn new(dir: impl Into<PathBuf>) -> Self {
+        Self {
+            dir: dir.into(),
+            ttl: Duration::from_secs(24 * 60 * 60),
+            max_size: 256 * 1024 * 1024,
+            offline: false,
+        }
+    }
+
+    /// Sets how long a page is served from the cache before it is revalidated.
+    pub fn ttl(mut self, ttl: Duration) -> Self {
+        self.ttl = ttl;
+        self
+    }
+
+    /// Sets the maximum size of the cache, in bytes. The least recently stored pages are
+    /// removed when the cache grows bigger.
+    pub fn max_size(mut self, bytes: u64) -> Self {
+        self.max_size = bytes;
+        self
+    }
+
+    /// Sets whether the pages are only served from the cache, whatever their age. A page
+    /// that is not cached fails with [`Error::CacheMiss`](crate::Error::CacheMiss).
+    ///
+    /// Useful to replay the imports in the tests without touching the network.
+    pub fn offline(mut self, offline: bool) -> Self {
+        self.offline = offline;
+        self
+    }
+}
+
+/// The validators sent with a conditional request.
+#[derive(Clone, Debug, Default, PartialEq)]
+pub(crate) struct Validators {
+    pub(crate) etag: Option<String>,
+    pub(crate) last_modified: Option<String>,
+}
+
+/// The answer to a conditional request.
+#[derive(Debug, PartialEq)]
+pub(crate) enum Fetched {
+    NotModified,
+    Body {
+        body: String,
+        validators: Validators,
+    },
+}
+
+#[derive(Deserialize, Serialize)]
+struct Entry {
+    url: String,
+    etag: Option<String>,
+    last_modified: Option<String>,
+    stored_at: u64,
+    body: String,
+}
+
+/// What to do with a request, given the cached page.
+enum Lookup {
+    /// The cached page is returned as is.
+    Hit(String),
+    /// The page is requested, conditionally if it is cached.
+    Fetch(Option<Entry>, Validators),
+}
+
+/// Wraps an [`HttpClient`] to serve the pages from the disk when possible.
+pub(crate) struct CachedHttpClient<C> {
+    inner: C,
+    config: CacheConfig,
+}
+
+impl<C: HttpClient + Sync + Send> CachedHttpClient<C> {
+    pub(crate) fn new(inner: C, config: CacheConfig) -> Result<Self> {
+        fs::create_dir_all(&config.dir)?;
+        Ok(Self { inner, config })
+    }
+
+    fn lookup(&self, url: &str) -> Result<Lookup> {
+        let entry = self.read(url);
+
+        if self.config.offline {
+            return match entry {
+                Some(entry) => Ok(Lookup::Hit(entry.body)),
+                None => Err(Error::CacheMiss {
+                    url: url.to_string(),
+                }),
+            };
+        }
+
+        Ok(match entry {
+            Some(entry) if now().saturating_sub(entry.stored_at) < self.config.ttl.as_secs() => {
+                Lookup::Hit(entry.body)
+            }
+            Some(entry) => {
+                let validators = Validators {
+                    etag: entry.etag.clone(),
+                    last_modified: entry.last_modified.clone(),
+                };
+                Lookup::Fetch(Some(entry), validators)
+            }
+            None => Lookup::Fetch(None, Validators::default()),
+        })
+    }
+
+    /// Stores the page that was fetched and returns it.
+    fn store(&self, url: &str, entry: Option<Entry>, fetched: Fetched) -> Result<String> {
+        let entry = match (fetched, entry) {
+            (Fetched::NotModified, Some(entry)) => Entry {
+                stored_at: now(),
+                ..entry
+            },
+            (Fetched::NotModified, None) => {
+                // The request was not conditional, so the server should not have sent that.
+                return Err(Error::HttpStatus {
+                    status: 304,
+                    url: url.to_string(),
+                });
+            }
+            (Fetched::Body { body, validators }, _) => Entry {
+                url: url.to_string(),
+                etag: validators.etag,
+                last_modified: validators.last_modified,
+                stored_at: now(),
+                body,
+            },
+        };
+
+        // Failing to write the cache does not fail the scrape.
+        let _ = self.write(&entry);
+        Ok(entry.body)
+    }
+
+    fn path(&self, url: &str) -> PathBuf {
+        self.config.dir.join(format!("{:016x}.json", fnv1a(url)))
+    }
+
+    fn read(&self, url: &str) -> Option<Entry> {
+        let content = fs::read(self.path(url)).ok()?;
+        serde_json::from_slice::<Entry>(&content)
+            .ok()
+            .filter(|entry| entry.url == url)
+    }
+
+    fn write(&self, entry: &Entry) -> Result<()> {
+        let content = serde_json::to_vec(entry)?;
+        if content.len() as u64 > self.config.max_size {
+            return Ok(());
+        }
+
+        let path = self.path(&entry.url);
+        let tmp = path.with_extension("tmp");
+        fs::write(&tmp, content)?;
+        fs::rename(&tmp, &path)?;
+
+        evict(&self.config.dir, self.config.max_size)
+    }
+}
+
+#[async_trait::async_trait]
+impl<C: HttpClient + Sync + Send> HttpClient for CachedHttpClient<C> {
+    async fn get_async<'a>(&'a self, host: Website, url: &str) -> Result<String> {
+        let (entry, validators) = match self.lookup(url)? {
+            Lookup::Hit(body) => return Ok(body),
+            Lookup::Fetch(entry, validators) => (entry, validators),
+        };
+        let fetched = self
+            .inner
+            .get_conditional_async(host, url, &validators)
+            .await?;
+        self.store(url, entry, fetched)
+    }
+
+    fn get(&self, host: Website, url: &str) -> Result<String> {
+        let (entry, validators) = match self.lookup(url)? {
+            Lookup::Hit(body) => return Ok(body),
+            Lookup::Fetch(entry, validators) => (entry, validators),
+        };
+        let fetched = self.inner.get_conditional(host, url, &validators)?;
+        self.store(url, entry, fetched)
+    }
+}
+
+/// Removes the oldest pages until the cache fits in its maximum size.
+fn evict(dir: &Path, max_size: u64) -> Result<()> {
+    let mut files: Vec<(SystemTime, u64, PathBuf)> = fs::read_dir(dir)?
+        .filter_map(|entry| {
+            let entry = entry.ok()?;
+            let path = entry.path();
+            if path.extension().is_none_or(|ext| ext != "json") {
+                return None;
+            }
+            let metadata = entry.metadata().ok()?;
+            Some((metadata.modified().ok()?, metadata.len(), path))
+        })
+        .collect();
+
+    let mut size: u64 = files.iter().map(|(_, len, _)| len).sum();
+    files.sort_by_key(|(modified, _, _)| *modified);
+    for (_, len, path) in files {
+        if size <= max_size {
+            break;
+        }
+        fs::remove_file(path)?;
+        size -= len;
+    }
+    Ok(())
+}
+
+/// The 64-bit FNV-1a hash, which unlike the hasher of the standard library is stable
+/// across Rust versions.
+fn fnv1a(s: &str) -> u64 {
+    s.bytes().fold(0xcbf29ce484222325, |hash, b| {
+        (hash ^ b as u64).wrapping_mul(0x100000001b3)
+    })
+}
+
+fn now() -> u64 {
+    SystemTime::now()
+        .duration_since(UNIX_EPOCH)
+        .map(|d| d.as_secs())
+        .unwrap_or_default()
+}
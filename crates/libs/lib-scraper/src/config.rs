@@ -0,0 +1,213 @@
+use std::time::Duration;
+
+use crate::{
+    cache::CacheConfig, heuristic::DEFAULT_MIN_CONFIDENCE, politeness::Rate, registry::Registry,
+    report::ParseMode, Result, Scraper,
+};
+
+/// The user agent sent by default. Many recipe websites answer 403 to HTTP clients that do
+/// not identify as a browser.
+pub const DEFAULT_USER_AGENT: &str =
+    "Mozilla/5.0 (X11; Linux x86_64; rv:130.0) Gecko/20100101 Firefox/130.0";
+
+/// The settings of a [`Scraper`].
+///
+/// ```ignore
+/// let scraper = ScraperConfig::default()
+///     .user_agent("Recipya")
+///     .max_retries(5)
+///     .proxy("socks5://127.0.0.1:9050")
+///     .build()?;
+/// ```
+#[derive(Clone, Debug)]
+pub struct ScraperConfig {
+    pub(crate) user_agent: String,
+    pub(crate) connect_timeout: Duration,
+    pub(crate) read_timeout: Duration,
+    pub(crate) max_retries: u32,
+    pub(crate) initial_backoff: Duration,
+    pub(crate) max_backoff: Duration,
+    pub(crate) proxy: Option<String>,
+    pub(crate) max_body_size: usize,
+    pub(crate) parse_mode: ParseMode,
+    pub(crate) rate_limit: Option<Rate>,
+    pub(crate) respect_robots_txt: bool,
+    pub(crate) cache: Option<CacheConfig>,
+    pub(crate) unknown_websites: bool,
+    pub(crate) meta_enrichment: bool,
+    pub(crate) text_normalization: bool,
+    pub(crate) min_confidence: f32,
+    pub(crate) registry: Registry,
+}
+
+impl Default for ScraperConfig {
+    fn default() -> Self {
+        Self {
+            user_agent: DEFAULT_USER_AGENT.to_string(),
+            connect_timeout: Duration::from_secs(10),
+            read_timeout: Duration::from_secs(30),
+            max_retries: 3,
+            initial_backoff: Duration::from_millis(500),
+            max_backoff: Duration::from_secs(30),
+            proxy: None,
+            max_body_size: 10 * 1024 * 1024,
+            parse_mode: ParseMode::Lenient,
+            rate_limit: Some(Rate {
+                per_second: 1.0,
+                burst: 5,
+            }),
+            respect_robots_txt: true,
+            cache: None,
+            unknown_websites: false,
+            meta_enrichment: true,
+            text_normalization: true,
+            min_confidence: DEFAULT_MIN_CONFIDENCE,
+            registry: Registry::default(),
+        }
+    }
+}
+
+impl ScraperConfig {
+    /// Sets the `User-Agent` header of every request.
+    pub fn user_agent(mut self, user_agent: impl Into<String>) -> Self {
+        self.user_agent = user_agent.into();
+        self
+    }
+
+    /// Sets how long to wait for the connection to the website to be established.
+    pub fn connect_timeout(mut self, timeout: Duration) -> Self {
+        self.connect_timeout = timeout;
+        self
+    }
+
+    /// Sets how long to wait for the website to send data before giving up.
+    pub fn read_timeout(mut self, timeout: Duration) -> Self {
+        self.read_timeout = timeout;
+        self
+    }
+
+    /// Sets how many times a request is retried after a 429, a 5xx, a timeout or a
+    /// connection error. Zero disables the retries.
+    pub fn max_retries(mut self, max_retries: u32) -> Self {
+        self.max_retries = max_retries;
+        self
+    }
+
+    /// Sets the delay before the first retry. The delay doubles on every retry, up to the
+    /// maximum backoff.
+    ///
+    /// The delay of a `Retry-After` header is honored as given. The request fails with the
+    /// status of the response when that delay is longer than the maximum backoff.
+    pub fn backoff(mut self, initial: Duration, max: Duration) -> Self {
+        self.initial_backoff = initial;
+        self.max_backoff = max;
+        self
+    }
+
+    /// Routes every request through the proxy, e.g. `http://10.0.0.1:3128` or
+    /// `socks5://127.0.0.1:9050`.
+    pub fn proxy(mut self, url: impl Into<String>) -> Self {
+        self.proxy = Some(url.into());
+        self
+    }
+
+    /// Sets the maximum size of a page, in bytes. Bigger pages fail with
+    /// [`Error::BodyTooLarge`](crate::Error::BodyTooLarge).
+    pub fn max_body_size(mut self, bytes: usize) -> Self {
+        self.max_body_size = bytes;
+        self
+    }
+
+    /// Sets how strictly the structured data is deserialized.
+    pub fn parse_mode(mut self, mode: ParseMode) -> Self {
+        self.parse_mode = mode;
+        self
+    }
+
+    /// Limits the requests sent to a single website to `per_second` on average, with at most
+    /// `burst` requests sent at once after a quiet period.
+    ///
+    /// A `Crawl-delay` in the `robots.txt` of the website slows the rate further.
+    pub fn rate_limit(mut self, per_second: f64, burst: u32) -> Self {
+        self.rate_limit = (per_second > 0.0).then_some(Rate {
+            per_second,
+            burst: burst.max(1),
+        });
+        self
+    }
+
+    /// Sends the requests as fast as the websites answer them.
+    pub fn without_rate_limit(mut self) -> Self {
+        self.rate_limit = None;
+        self
+    }
+
+    /// Sets whether the `robots.txt` of every website is fetched and the paths it disallows
+    /// refused with [`Error::DisallowedByRobots`](crate::Error::DisallowedByRobots).
+    pub fn respect_robots_txt(mut self, respect: bool) -> Self {
+        self.respect_robots_txt = respect;
+        self
+    }
+
+    /// Keeps the fetched pages on the disk. The cache is disabled by default.
+    pub fn cache(mut self, cache: CacheConfig) -> Self {
+        self.cache = Some(cache);
+        self
+    }
+
+    /// Sets whether the websites that are not supported are scraped from their structured
+    /// data rather than rejected with [`Error::UnknownWebsite`](crate::Error::UnknownWebsite).
+    ///
+    /// Their recipes are flagged with [`ScrapeReport::unverified_site`](crate::ScrapeReport::unverified_site).
+    pub fn unknown_websites(mut self, allow: bool) -> Self {
+        self.unknown_websites = allow;
+        self
+    }
+
+    /// Whether the image, description, URL and language missing from a recipe are taken
+    /// from the meta tags of its page. Enabled by default.
+    ///
+    /// The fields filled are listed in [`ScrapeReport::filled_fields`](crate::ScrapeReport::filled_fields).
+    pub fn meta_enrichment(mut self, enable: bool) -> Self {
+        self.meta_enrichment = enable;
+        self
+    }
+
+    /// Whether to clean the text of the recipes: decode the HTML entities, strip the tags,
+    /// collapse the whitespace and normalize the unicode fractions and quotes. Enabled by default.
+    pub fn text_normalization(mut self, enable: bool) -> Self {
+        self.text_normalization = enable;
+        self
+    }
+
+    /// Sets the confidence, from 0 to 1, below which the recipe guessed from the layout of a
+    /// page without a recipe in its structured data is rejected with
+    /// [`Error::NoStructuredData`](crate::Error::NoStructuredData) or
+    /// [`Error::NotARecipe`](crate::Error::NotARecipe). Defaults to 0.75, which
+    /// requires the page to have a list of instructions besides the ingredients. Above 1, no
+    /// recipe is guessed.
+    ///
+    /// The confidence of the recipes guessed is in [`ScrapeReport::confidence`](crate::ScrapeReport::confidence).
+    pub fn min_confidence(mut self, confidence: f32) -> Self {
+        self.min_confidence = confidence;
+        self
+    }
+
+    /// Adds the websites of the registry to the built-in ones.
+    pub fn registry(mut self, registry: Registry) -> Self {
+        self.registry = registry;
+        self
+    }
+
+    /// Creates the scraper.
+    pub fn build(self) -> Result<Scraper> {
+        Scraper::new(self)
+    }
+
+    /// The delay before the retry that follows the given number of attempts.
+    pub(crate) fn backoff_delay(&self, attempt: u32) -> Duration {
+        self.initial_backoff
+            .saturating_mul(2u32.saturating_pow(attempt))
+            .min(self.max_backoff)
+    }
+}
@@ -0,0 +1,475 @@
+//! Discovery of the recipe pages of a website.
+//!
+//! A crawl starts from the sitemaps the `robots.txt` of the website lists, `/sitemap.xml`
+//! otherwise, and from its RSS or Atom feed. Sitemap indexes are followed down to their
+//! sitemaps, and category pages down to the pages they link to, as deep as the
+//! [`CrawlConfig`] allows. The home page is only read when neither a sitemap nor a feed could.
+
+use regex::Regex;
+use scraper::{Html, Selector};
+use std::{
+    collections::{HashSet, VecDeque},
+    sync::OnceLock,
+};
+use tracing::warn;
+use url::Url;
+
+use crate::{
+    instructions::strip_html, robots, schema::recipe::RecipeSchema, Error, Result, Scraper,
+};
+
+/// The segments of the paths of the pages that are not recipes. A segment is also excluded
+/// when it starts with one of these words, e.g. "privacy-policy" or "about-us".
+const EXCLUDED_SEGMENTS: [&str; 16] = [
+    "about",
+    "account",
+    "author",
+    "cart",
+    "category",
+    "contact",
+    "feed",
+    "login",
+    "page",
+    "privacy",
+    "search",
+    "shop",
+    "tag",
+    "tags",
+    "wp-content",
+    "wp-json",
+];
+
+/// The extensions of the files that are not pages.
+const EXCLUDED_EXTENSIONS: [&str; 12] = [
+    "css", "gif", "gz", "jpeg", "jpg", "js", "mp4", "pdf", "png", "svg", "webp", "xml",
+];
+
+/// The words of the names of the sitemaps that list anything but posts.
+const EXCLUDED_SITEMAPS: [&str; 10] = [
+    "attachment",
+    "author",
+    "category",
+    "image",
+    "page-sitemap",
+    "posts-page",
+    "product",
+    "tag",
+    "taxonom",
+    "user",
+];
+
+/// The segments of the paths of the pages that list other pages.
+const LISTING_SEGMENTS: [&str; 5] = ["categories", "category", "page", "tag", "tags"];
+
+/// The paths of the indexes of the recipes of a website.
+const RECIPE_INDEXES: [&str; 3] = ["recipe-index", "recipes", "recipe"];
+
+/// Configures how far a crawl goes.
+#[derive(Clone, Debug)]
+pub struct CrawlConfig {
+    pub(crate) max_depth: usize,
+    pub(crate) max_urls: Option<usize>,
+    pub(crate) known_urls: HashSet<String>,
+}
+
+impl Default for CrawlConfig {
+    fn default() -> Self {
+        Self {
+            max_depth: 3,
+            max_urls: None,
+            known_urls: HashSet::new(),
+        }
+    }
+}
+
+impl CrawlConfig {
+    /// Sets how many links away from the sitemaps, feed or page the crawl starts from the
+    /// documents read can be. Zero only reads the documents it starts from.
+    pub fn max_depth(mut self, depth: usize) -> Self {
+        self.max_depth = depth;
+        self
+    }
+
+    /// Sets the number of recipe pages after which the crawl stops.
+    pub fn max_urls(mut self, max: usize) -> Self {
+        self.max_urls = Some(max);
+        self
+    }
+
+    /// Sets the pages to leave out, e.g. the ones of the recipes already imported. The scheme,
+    /// the `www.` of the host and the trailing slash do not matter.
+    pub fn known_urls<I, S>(mut self, urls: I) -> Self
+    where
+        I: IntoIterator<Item = S>,
+        S: Into<String>,
+    {
+        self.known_urls = urls
+            .into_iter()
+            .filter_map(|url| Url::parse(&url.into()).ok())
+            .map(|url| key(&url))
+            .collect();
+        self
+    }
+}
+
+/// A document read during the crawl.
+enum Document {
+    /// The sitemaps of a sitemap index.
+    SitemapIndex(Vec<String>),
+
+    /// The pages of a sitemap or the posts of a feed.
+    Pages(Vec<String>),
+
+    /// The links of an HTML page.
+    Html(Vec<String>),
+}
+
+impl Scraper {
+    /// Finds the recipe pages of the website at the URL.
+    ///
+    /// The URL is either the root of the website, whose sitemaps and feed are then read, or
+    /// the URL of a sitemap, feed or category page. Only the pages of the same website that
+    /// look like recipes are returned, without the known ones. The documents are read breadth
+    /// first, so the latest posts of the feed come before the pages of the sitemaps.
+    /// Fails when none of the documents the crawl starts from could be fetched.
+    pub async fn discover(&self, url: &str, config: &CrawlConfig) -> Result<Vec<String>> {
+        let start = Url::parse(url).map_err(|ex| Error::Parse(ex.to_string()))?;
+        let mut crawl = Crawl::new(&start, config);
+
+        let is_root = start.path() == "/" && start.query().is_none();
+        if is_root {
+            let seeds = self.seeds(&start).await;
+            crawl.run(self, seeds).await;
+        }
+        if crawl.documents == 0 {
+            crawl.error = None;
+            crawl.run(self, vec![start.to_string()]).await;
+        }
+
+        match crawl.error {
+            Some(ex) if crawl.documents == 0 => Err(ex),
+            _ => Ok(crawl.found),
+        }
+    }
+
+    /// Finds the recipe pages of the website at the URL like [`Scraper::discover`] and scrapes
+    /// them like [`Scraper::scrape_many`].
+    pub async fn crawl(
+        &self,
+        url: &str,
+        config: &CrawlConfig,
+    ) -> Result<Vec<(String, Result<RecipeSchema>)>> {
+        let urls = self.discover(url, config).await?;
+        let recipes = self.scrape_many(&urls).await;
+        Ok(urls.into_iter().zip(recipes).collect())
+    }
+
+    /// The sitemaps listed in the `robots.txt` of the website, or its `/sitemap.xml`, and
+    /// its feed.
+    async fn seeds(&self, root: &Url) -> Vec<String> {
+        let mut seeds = match root.join("/robots.txt") {
+            Ok(url) => match self.fetch_async(url.as_str()).await {
+                Ok((_, content)) => robots::sitemaps(&content),
+                Err(_) => Vec::new(),
+            },
+            Err(_) => Vec::new(),
+        };
+        if seeds.is_empty() {
+            seeds.extend(root.join("/sitemap.xml").ok().map(String::from));
+        }
+        seeds.extend(root.join("/feed").ok().map(String::from));
+        seeds
+    }
+}
+
+/// The state of a crawl.
+struct Crawl<'a> {
+    config: &'a CrawlConfig,
+    host: String,
+    visited: HashSet<String>,
+    seen: HashSet<String>,
+    found: Vec<String>,
+    documents: usize,
+    error: Option<Error>,
+}
+
+impl<'a> Crawl<'a> {
+    fn new(start: &Url, config: &'a CrawlConfig) -> Self {
+        Self {
+            config,
+            host: site(start),
+            visited: HashSet::new(),
+            seen: HashSet::new(),
+            found: Vec::new(),
+            documents: 0,
+            error: None,
+        }
+    }
+
+    /// Reads the documents breadth first, from the seeds down to the maximum depth.
+    async fn run(&mut self, scraper: &Scraper, seeds: Vec<String>) {
+        let mut queue: VecDeque<(String, usize)> = seeds.into_iter().map(|url| (url, 0)).collect();
+
+        while let Some((url, depth)) = queue.pop_front() {
+            if self.is_full() {
+                return;
+            }
+            if !self.visited.insert(url.clone()) {
+                continue;
+            }
+
+            let content = match scraper.fetch_async(&url).await {
+                Ok((_, content)) => content,
+                Err(ex) => {
+                    if depth > 0 {
+                        warn!("Could not crawl {url}: {ex}");
+                    }
+                    self.error.get_or_insert(ex);
+                    continue;
+                }
+            };
+            self.documents += 1;
+
+            let Ok(base) = Url::parse(&url) else {
+                continue;
+            };
+            let can_follow = depth < self.config.max_depth;
+            match parse_document(&content, &base) {
+                Document::SitemapIndex(sitemaps) => {
+                    if can_follow {
+                        queue.extend(
+                            sitemaps
+                                .into_iter()
+                                .filter(|sitemap| is_recipe_sitemap(sitemap))
+                                .map(|sitemap| (sitemap, depth + 1)),
+                        );
+                    }
+                }
+                Document::Pages(pages) => {
+                    for page in pages {
+                        self.add(&page);
+                    }
+                }
+                Document::Html(links) => {
+                    for link in links {
+                        let Ok(url) = Url::parse(&link) else {
+                            continue;
+                        };
+                        if site(&url) != self.host {
+                            continue;
+                        }
+                        if is_listing(&url) {
+                            if can_follow {
+                                queue.push_back((link, depth + 1));
+                            }
+                        } else {
+                            self.add(&link);
+                        }
+                    }
+                }
+            }
+        }
+    }
+
+    /// Keeps the page when it looks like a recipe of the website and is neither known nor
+    /// already found.
+    fn add(&mut self, page: &str) {
+        if self.is_full() {
+            return;
+        }
+
+        let Ok(mut url) = Url::parse(page) else {
+            return;
+        };
+        url.set_fragment(None);
+        if site(&url) != self.host || !looks_like_recipe(&url) {
+            return;
+        }
+
+        let key = key(&url);
+        if !self.config.known_urls.contains(&key) && self.seen.insert(key) {
+            self.found.push(url.into());
+        }
+    }
+
+    fn is_full(&self) -> bool {
+        self.config
+            .max_urls
+            .is_some_and(|max| self.found.len() >= max)
+    }
+}
+
+/// Tells sitemaps, sitemap indexes and feeds apart from HTML pages and extracts their links.
+fn parse_document(content: &str, base: &Url) -> Document {
+    let head: String = content
+        .chars()
+        .take(2048)
+        .collect::<String>()
+        .to_lowercase();
+
+    if head.contains("<sitemapindex") {
+        Document::SitemapIndex(locations(content, base))
+    } else if head.contains("<urlset") {
+        Document::Pages(locations(content, base))
+    } else if head.contains("<rss") || head.contains("<rdf:rdf") {
+        Document::Pages(rss_links(content, base))
+    } else if head.contains("<feed") {
+        Document::Pages(atom_links(content, base))
+    } else {
+        Document::Html(html_links(content, base))
+    }
+}
+
+/// The URLs in the `<loc>` elements of a sitemap or sitemap index.
+fn locations(content: &str, base: &Url) -> Vec<String> {
+    static INSTANCE: OnceLock<Regex> = OnceLock::new();
+    let re = INSTANCE.get_or_init(|| Regex::new(r"(?is)<loc>(.*?)</loc>").unwrap());
+
+    re.captures_iter(content)
+        .filter_map(|caps| resolve(base, &xml_text(&caps[1])))
+        .collect()
+}
+
+/// The links of the items of an RSS feed.
+fn rss_links(content: &str, base: &Url) -> Vec<String> {
+    static ITEM: OnceLock<Regex> = OnceLock::new();
+    static LINK: OnceLock<Regex> = OnceLock::new();
+    let item_re = ITEM.get_or_init(|| Regex::new(r"(?is)<item\b.*?</item>").unwrap());
+    let link_re = LINK.get_or_init(|| Regex::new(r"(?is)<link>(.*?)</link>").unwrap());
+
+    item_re
+        .find_iter(content)
+        .filter_map(|item| link_re.captures(item.as_str()))
+        .filter_map(|caps| resolve(base, &xml_text(&caps[1])))
+        .collect()
+}
+
+/// The alternate links of the entries of an Atom feed.
+fn atom_links(content: &str, base: &Url) -> Vec<String> {
+    static ENTRY: OnceLock<Regex> = OnceLock::new();
+    static LINK: OnceLock<Regex> = OnceLock::new();
+    let entry_re = ENTRY.get_or_init(|| Regex::new(r"(?is)<entry\b.*?</entry>").unwrap());
+    let link_re = LINK.get_or_init(|| {
+        Regex::new(r#"(?is)<link\b([^>]*?)href\s*=\s*["']([^"']+)["']([^>]*)>"#).unwrap()
+    });
+
+    entry_re
+        .find_iter(content)
+        .filter_map(|entry| {
+            link_re.captures_iter(entry.as_str()).find_map(|caps| {
+                let attributes = format!("{}{}", &caps[1], &caps[3]).to_lowercase();
+                let is_alternate = !attributes.contains("rel") || attributes.contains("alternate");
+                is_alternate
+                    .then(|| resolve(base, &xml_text(&caps[2])))
+                    .flatten()
+            })
+        })
+        .collect()
+}
+
+/// The links of an HTML page.
+fn html_links(content: &str, base: &Url) -> Vec<String> {
+    let Ok(sel) = Selector::parse("a[href]") else {
+        return Vec::new();
+    };
+
+    Html::parse_document(content)
+        .select(&sel)
+        .filter_map(|el| el.value().attr("href"))
+        .filter_map(|href| resolve(base, href.trim()))
+        .collect()
+}
+
+/// The text of an XML element, out of its CDATA section and with its entities decoded.
+fn xml_text(s: &str) -> String {
+    let s = s.trim();
+    match s
+        .strip_prefix("<![CDATA[")
+        .and_then(|s| s.strip_suffix("]]>"))
+    {
+        Some(data) => data.trim().to_string(),
+        None => strip_html(s),
+    }
+}
+
+/// Resolves the link against the URL of the document it is in, without its fragment.
+fn resolve(base: &Url, link: &str) -> Option<String> {
+    let mut url = base.join(link).ok()?;
+    if !matches!(url.scheme(), "http" | "https") {
+        return None;
+    }
+    url.set_fragment(None);
+    Some(url.into())
+}
+
+/// The host of the URL without its `www.`, which tells whether two URLs are of the same website.
+fn site(url: &Url) -> String {
+    url.host_str()
+        .unwrap_or_default()
+        .trim_start_matches("www.")
+        .to_lowercase()
+}
+
+/// Identifies a page regardless of its scheme, the `www.` of its host and its trailing slash.
+fn key(url: &Url) -> String {
+    let mut key = format!("{}{}", site(url), url.path().trim_end_matches('/'));
+    if let Some(query) = url.query() {
+        key.push('?');
+        key.push_str(query);
+    }
+    key
+}
+
+fn segments(url: &Url) -> Vec<String> {
+    url.path_segments()
+        .map(|segments| {
+            segments
+                .filter(|segment| !segment.is_empty())
+                .map(str::to_lowercase)
+                .collect()
+        })
+        .unwrap_or_default()
+}
+
+/// Whether the page could be a recipe, i.e. it is neither the home page, a listing, a file nor
+/// one of the usual pages of a website.
+fn looks_like_recipe(url: &Url) -> bool {
+    let segments = segments(url);
+    let Some(last) = segments.last() else {
+        return false;
+    };
+
+    let is_file = last
+        .rsplit_once('.')
+        .is_some_and(|(_, extension)| EXCLUDED_EXTENSIONS.contains(&extension));
+    let is_excluded = segments.iter().any(|segment| {
+        let first_word = segment.split(['-', '_']).next().unwrap_or_default();
+        EXCLUDED_SEGMENTS.contains(&segment.as_str()) || EXCLUDED_SEGMENTS.contains(&first_word)
+    });
+    let is_paginated = url
+        .query_pairs()
+        .any(|(key, _)| key == "page" || key == "paged" || key == "s");
+
+    !is_file && !is_excluded && !is_paginated
+}
+
+/// Whether the page lists other pages, e.g. a category page or the second page of a blog.
+fn is_listing(url: &Url) -> bool {
+    let segments = segments(url);
+    let is_listing_path = match segments.as_slice() {
+        [index] if RECIPE_INDEXES.contains(&index.as_str()) => true,
+        _ => segments
+            .iter()
+            .any(|segment| LISTING_SEGMENTS.contains(&segment.as_str())),
+    };
+    is_listing_path
+        || url
+            .query_pairs()
+            .any(|(key, _)| key == "page" || key == "paged")
+}
+
+/// Whether the sitemap of a sitemap index may list posts.
+fn is_recipe_sitemap(url: &str) -> bool {
+    let name = url.rsplit('/').next().unwrap_or_default().to_lowercase();
+    !name.ends_with(".gz") && !EXCLUDED_SITEMAPS.iter().any(|word| name.contains(word))
+}
@@ -1 +1,127 @@
+//! Site-specific extraction.
+//!
+//! Every website first goes through the generic JSON-LD and microdata pass. A [`SiteExtractor`]
+//! registered for the website then gets a chance to patch the fields its structured data gets
+//! wrong or omits, or to build the recipe from scratch when the page has none.
 
+use scraper::{ElementRef, Html, Selector};
+use serde::Deserialize;
+use std::{collections::HashMap, sync::Arc};
+use url::Url;
+
+use crate::{
+    schema::{
+        common::{CreativeWorkOrItemListOrText, HowTo, ImageObjectOrUrl, TextOrTextObject},
+        recipe::RecipeSchema,
+        AtType,
+    },
+    websites::Website,
+    Result,
+};
+
+/// Extracts or corrects recipe fields for a given website.
+pub trait SiteExtractor: Send + Sync {
+    /// Patches the recipe found by the generic pass.
+    ///
+    /// The recipe is empty when the page has no structured data.
+    fn extract(&self, doc: &Html, url: &str, recipe: &mut RecipeSchema) -> Result<()>;
+}
+
+/// A [`SiteExtractor`] that overrides fields with the content of the elements matched by
+/// CSS selectors.
+///
+/// Fields whose selector is `None` or matches nothing are left untouched.
+#[derive(Clone, Debug, Default, Deserialize, PartialEq)]
+#[serde(default, deny_unknown_fields)]
+pub struct SelectorExtractor {
+    /// Selects the element holding the name of the recipe.
+    pub name: Option<String>,
+
+    /// Selects the element holding the description of the recipe.
+    pub description: Option<String>,
+
+    /// Selects the `img` element of the recipe's main image.
+    pub image: Option<String>,
+
+    /// Selects one element per ingredient.
+    pub ingredients: Option<String>,
+
+    /// Selects one element per instruction step.
+    pub instructions: Option<String>,
+}
+
+impl SiteExtractor for SelectorExtractor {
+    fn extract(&self, doc: &Html, url: &str, recipe: &mut RecipeSchema) -> Result<()> {
+        if let Some(name) = select_first(doc, &self.name)? {
+            recipe.name = Some(element_text(name));
+        }
+
+        if let Some(description) = select_first(doc, &self.description)? {
+            recipe.description = Some(TextOrTextObject::Text(element_text(description)));
+        }
+
+        if let Some(image) = select_first(doc, &self.image)? {
+            let src = image
+                .value()
+                .attr("data-src")
+                .or_else(|| image.value().attr("src"));
+
+            if let Some(image) = src.and_then(|src| Url::parse(url).ok()?.join(src).ok()) {
+                recipe.image = Some(ImageObjectOrUrl::Url(image));
+            }
+        }
+
+        let ingredients = select_texts(doc, &self.ingredients)?;
+        if !ingredients.is_empty() {
+            recipe.recipe_ingredient = Some(ingredients);
+        }
+
+        let instructions = select_texts(doc, &self.instructions)?;
+        if !instructions.is_empty() {
+            recipe.recipe_instructions = Some(CreativeWorkOrItemListOrText::ItemList(
+                instructions
+                    .into_iter()
+                    .map(|text| HowTo {
+                        at_type: AtType::HowToStep,
+                        text,
+                        ..Default::default()
+                    })
+                    .collect(),
+            ));
+        }
+
+        Ok(())
+    }
+}
+
+fn select_first<'a>(doc: &'a Html, selector: &Option<String>) -> Result<Option<ElementRef<'a>>> {
+    match selector {
+        Some(selector) => Ok(doc.select(&Selector::parse(selector)?).next()),
+        None => Ok(None),
+    }
+}
+
+fn select_texts(doc: &Html, selector: &Option<String>) -> Result<Vec<String>> {
+    match selector {
+        Some(selector) => Ok(doc
+            .select(&Selector::parse(selector)?)
+            .map(element_text)
+            .filter(|text| !text.is_empty())
+            .collect()),
+        None => Ok(Vec::new()),
+    }
+}
+
+pub(crate) fn element_text(el: ElementRef) -> String {
+    el.text()
+        .flat_map(str::split_whitespace)
+        .collect::<Vec<_>>()
+        .join(" ")
+}
+
+/// The extractors of the websites whose structured data needs help.
+///
+/// Websites not listed here rely on the generic extraction alone.
+pub(crate) fn builtin_extractors() -> HashMap<Website, Arc<dyn SiteExtractor>> {
+    HashMap::new()
+}
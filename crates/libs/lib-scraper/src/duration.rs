@@ -0,0 +1,315 @@
+//! Parsing of the cook, prep and total times of recipes.
+//!
+//! Websites do not always follow ISO 8601: times such as "30 minutes", "1 hr 15 mins", "1h30",
+//! "PT" or "P0DT1H" are common. They are read as well as possible, and the times that make no
+//! sense are logged and dropped instead of failing the whole recipe.
+
+use regex::Regex;
+use serde::{
+    de::{Error, IgnoredAny, MapAccess, SeqAccess, Visitor},
+    Deserializer,
+};
+use std::{fmt::Formatter, str::FromStr, sync::OnceLock};
+use tracing::warn;
+
+use crate::{ingredients::replace_unicode_fractions, schema::recipe::RecipeSchema};
+
+const SECONDS_IN_DAY: f64 = 86_400.0;
+const SECONDS_IN_HOUR: f64 = 3600.0;
+const SECONDS_IN_MINUTE: f64 = 60.0;
+
+impl RecipeSchema {
+    /// The preparation time, in seconds.
+    pub fn prep_seconds(&self) -> Option<u64> {
+        self.prep_time.map(to_seconds)
+    }
+
+    /// The cooking time, in seconds.
+    pub fn cook_seconds(&self) -> Option<u64> {
+        self.cook_time.map(to_seconds)
+    }
+
+    /// The total time, in seconds. Falls back to the sum of the preparation and cooking times
+    /// when the recipe does not state it.
+    pub fn total_seconds(&self) -> Option<u64> {
+        match (self.total_time, self.prep_seconds(), self.cook_seconds()) {
+            (Some(total), _, _) => Some(to_seconds(total)),
+            (None, None, None) => None,
+            (None, prep, cook) => Some(prep.unwrap_or_default() + cook.unwrap_or_default()),
+        }
+    }
+}
+
+/// The length of the duration in seconds. Years count 365 days and months 30 days.
+pub fn to_seconds(duration: iso8601::Duration) -> u64 {
+    std::time::Duration::from(duration).as_secs()
+}
+
+/// Parses a duration written in ISO 8601, e.g. "PT1H15M", or as words, e.g. "1 hr 15 mins",
+/// "1h30" or "20 Minuten". A bare number is a number of minutes.
+///
+/// Returns `None` when no time can be read from the text.
+pub fn parse_duration(s: &str) -> Option<iso8601::Duration> {
+    let s = s.trim();
+    if s.is_empty() || s.eq_ignore_ascii_case("P") || s.eq_ignore_ascii_case("PT") {
+        return None;
+    }
+
+    // A negative ISO 8601 duration, e.g. "PT-5M", is no time at all.
+    let mut chars = s.chars();
+    if matches!(chars.next(), Some('P' | 'p'))
+        && matches!(chars.next(), Some('T' | 't' | '-' | '0'..='9'))
+        && s.contains('-')
+    {
+        return None;
+    }
+
+    let iso_like = parse_iso_like(s);
+    if let Ok(duration) = iso8601::Duration::from_str(s) {
+        // The ISO 8601 parser reads decimal hours and minutes, e.g. "PT1.5H", as zero.
+        if iso_like.is_none_or(|seconds| seconds as u64 == to_seconds(duration)) {
+            return Some(duration);
+        }
+    }
+
+    iso_like.or_else(|| parse_words(s)).and_then(from_seconds)
+}
+
+/// Deserializes a duration given as text or as a number of minutes. The durations that cannot
+/// be read are logged and skipped.
+pub(crate) fn deserialize_lenient<'de, D>(
+    deserializer: D,
+) -> Result<Option<iso8601::Duration>, D::Error>
+where
+    D: Deserializer<'de>,
+{
+    struct DurationVisitor;
+
+    impl<'de> Visitor<'de> for DurationVisitor {
+        type Value = Option<iso8601::Duration>;
+
+        fn expecting(&self, formatter: &mut Formatter) -> std::fmt::Result {
+            formatter.write_str("a duration as text or as a number of minutes")
+        }
+
+        fn visit_str<E>(self, v: &str) -> Result<Self::Value, E>
+        where
+            E: Error,
+        {
+            let duration = parse_duration(v);
+            if duration.is_none() && !v.trim().is_empty() {
+                warn!("Skipping the duration {v:?}, which could not be parsed");
+            }
+            Ok(duration)
+        }
+
+        fn visit_u64<E>(self, v: u64) -> Result<Self::Value, E>
+        where
+            E: Error,
+        {
+            self.visit_f64(v as f64)
+        }
+
+        fn visit_i64<E>(self, v: i64) -> Result<Self::Value, E>
+        where
+            E: Error,
+        {
+            self.visit_f64(v as f64)
+        }
+
+        fn visit_f64<E>(self, v: f64) -> Result<Self::Value, E>
+        where
+            E: Error,
+        {
+            if let Some(duration) = (v.is_finite() && v >= 0.0)
+                .then(|| from_seconds(v * SECONDS_IN_MINUTE))
+                .flatten()
+            {
+                Ok(Some(duration))
+            } else {
+                warn!("Skipping the duration {v}, which is not a number of minutes");
+                Ok(None)
+            }
+        }
+
+        fn visit_bool<E>(self, v: bool) -> Result<Self::Value, E>
+        where
+            E: Error,
+        {
+            warn!("Skipping the duration {v}, which is not a time");
+            Ok(None)
+        }
+
+        fn visit_seq<A>(self, mut seq: A) -> Result<Self::Value, A::Error>
+        where
+            A: SeqAccess<'de>,
+        {
+            let mut duration = None;
+            while let Some(element) = seq.next_element::<serde_json::Value>()? {
+                if duration.is_none() {
+                    duration = deserialize_lenient(element).map_err(A::Error::custom)?;
+                }
+            }
+            Ok(duration)
+        }
+
+        fn visit_map<A>(self, mut map: A) -> Result<Self::Value, A::Error>
+        where
+            A: MapAccess<'de>,
+        {
+            while map.next_entry::<IgnoredAny, IgnoredAny>()?.is_some() {}
+            warn!("Skipping the duration given as an object");
+            Ok(None)
+        }
+
+        fn visit_none<E>(self) -> Result<Self::Value, E>
+        where
+            E: Error,
+        {
+            Ok(None)
+        }
+
+        fn visit_unit<E>(self) -> Result<Self::Value, E>
+        where
+            E: Error,
+        {
+            Ok(None)
+        }
+
+        fn visit_some<D>(self, deserializer: D) -> Result<Self::Value, D::Error>
+        where
+            D: Deserializer<'de>,
+        {
+            deserializer.deserialize_any(self)
+        }
+    }
+
+    deserializer.deserialize_any(DurationVisitor)
+}
+
+/// Parses the durations that look like ISO 8601 without following it, e.g. "pt20m",
+/// "PT1.5H" or "P0DT1H". Returns the number of seconds.
+fn parse_iso_like(s: &str) -> Option<f64> {
+    static INSTANCE: OnceLock<Regex> = OnceLock::new();
+    let re = INSTANCE.get_or_init(|| {
+        Regex::new(
+            r"(?i)^P(?:(\d+)Y)?(?:(\d+)M)?(?:(\d+)W)?(?:(\d+)D)?(?:T(?:(\d+(?:[.,]\d+)?)H)?(?:(\d+(?:[.,]\d+)?)M)?(?:(\d+(?:[.,]\d+)?)S)?)?$",
+        )
+        .unwrap()
+    });
+
+    let caps = re.captures(s)?;
+    if caps.iter().skip(1).all(|group| group.is_none()) {
+        return None;
+    }
+
+    let units = [
+        365.0 * SECONDS_IN_DAY,
+        30.0 * SECONDS_IN_DAY,
+        7.0 * SECONDS_IN_DAY,
+        SECONDS_IN_DAY,
+        SECONDS_IN_HOUR,
+        SECONDS_IN_MINUTE,
+        1.0,
+    ];
+    Some(
+        units
+            .iter()
+            .enumerate()
+            .filter_map(|(i, unit)| Some(parse_number(caps.get(i + 1)?.as_str())? * unit))
+            .sum(),
+    )
+}
+
+/// Parses durations written with words, e.g. "1 hr 15 mins", "1h30", "1 1/2 hours",
+/// "2 heures" or "1:30". Returns the number of seconds.
+fn parse_words(s: &str) -> Option<f64> {
+    static CLOCK: OnceLock<Regex> = OnceLock::new();
+    static TOKENS: OnceLock<Regex> = OnceLock::new();
+
+    let s = replace_unicode_fractions(s);
+    let s = s.as_str();
+
+    let clock = CLOCK.get_or_init(|| Regex::new(r"^(\d+):([0-5]\d)$").unwrap());
+    if let Some(caps) = clock.captures(s) {
+        let hours = parse_number(&caps[1])?;
+        let minutes = parse_number(&caps[2])?;
+        return Some(hours * SECONDS_IN_HOUR + minutes * SECONDS_IN_MINUTE);
+    }
+
+    if let Some(minutes) = parse_number(s) {
+        return Some(minutes * SECONDS_IN_MINUTE);
+    }
+
+    let tokens = TOKENS
+        .get_or_init(|| Regex::new(r"((?:\d+\s+)?\d+/\d+|\d+(?:[.,]\d+)?)\s*(\p{L}+)?").unwrap());
+    let mut seconds = None;
+    let mut previous_unit = None;
+    for caps in tokens.captures_iter(s) {
+        let Some(value) = parse_number(&caps[1]) else {
+            continue;
+        };
+
+        let unit = match caps.get(2) {
+            Some(word) => unit_seconds(word.as_str()),
+            // The minutes of "1h30".
+            None if previous_unit == Some(SECONDS_IN_HOUR) => Some(SECONDS_IN_MINUTE),
+            None => None,
+        };
+
+        if let Some(unit) = unit {
+            seconds = Some(seconds.unwrap_or_default() + value * unit);
+        }
+        previous_unit = unit;
+    }
+    seconds
+}
+
+/// The number of seconds in the unit, written in English, French, Spanish, Portuguese,
+/// German, Italian or Dutch.
+fn unit_seconds(word: &str) -> Option<f64> {
+    match word.to_lowercase().as_str() {
+        "d" | "day" | "days" | "j" | "jour" | "jours" | "día" | "días" | "dia" | "dias" | "tag"
+        | "tage" | "tagen" | "giorno" | "giorni" | "dag" | "dagen" => Some(SECONDS_IN_DAY),
+        "h" | "hr" | "hrs" | "hour" | "hours" | "heure" | "heures" | "hora" | "horas"
+        | "stunde" | "stunden" | "std" | "ora" | "ore" | "uur" => Some(SECONDS_IN_HOUR),
+        "m" | "mn" | "min" | "mins" | "minute" | "minutes" | "minuto" | "minutos" | "minuti"
+        | "minuut" | "minuten" => Some(SECONDS_IN_MINUTE),
+        "s" | "sec" | "secs" | "second" | "seconds" | "seconde" | "secondes" | "segundo"
+        | "segundos" | "sekunde" | "sekunden" | "secondo" | "secondi" | "seconden" => Some(1.0),
+        _ => None,
+    }
+}
+
+/// Parses a number written as a decimal, e.g. "1.5" or "1,5", a fraction, e.g. "1/2", or a
+/// mixed number, e.g. "1 1/2".
+fn parse_number(s: &str) -> Option<f64> {
+    let Some((whole_numerator, denominator)) = s.split_once('/') else {
+        return s.replace(',', ".").parse().ok();
+    };
+
+    let (whole, numerator) = match whole_numerator.split_once(char::is_whitespace) {
+        Some((whole, numerator)) => (whole.parse::<f64>().ok()?, numerator.trim()),
+        None => (0.0, whole_numerator),
+    };
+    let numerator: f64 = numerator.parse().ok()?;
+    let denominator: f64 = denominator.parse().ok()?;
+    (denominator != 0.0).then(|| whole + numerator / denominator)
+}
+
+/// The duration of the number of seconds, in hours, minutes and seconds. Returns `None` when
+/// the number of hours does not fit the duration.
+fn from_seconds(seconds: f64) -> Option<iso8601::Duration> {
+    let millis = (seconds * 1000.0).round() as u64;
+    let seconds = millis / 1000;
+
+    Some(iso8601::Duration::YMDHMS {
+        year: 0,
+        month: 0,
+        day: 0,
+        hour: u32::try_from(seconds / 3600).ok()?,
+        minute: (seconds % 3600 / 60) as u32,
+        second: (seconds % 60) as u32,
+        millisecond: (millis % 1000) as u32,
+    })
+}
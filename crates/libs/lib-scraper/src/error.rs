@@ -4,9 +4,44 @@ pub type Result<T> = core::result::Result<T, Error>;
 
 #[derive(Debug, From)]
 pub enum Error {
-    DomainNotImplemented,
-    LdJsonNotFound,
+    /// The scraper of the free functions was built before [`init`](crate::init) was called.
+    AlreadyInitialized,
+    /// The page answered with a captcha or a bot challenge instead of its content.
+    Blocked,
+    /// The page is bigger than the maximum body size of the [`ScraperConfig`](crate::ScraperConfig).
+    BodyTooLarge {
+        limit: usize,
+    },
+    /// The page is not in the cache, which is offline.
+    CacheMiss {
+        url: String,
+    },
+    /// The page has structured data, but it failed to deserialize.
+    Deserialize {
+        path: String,
+        message: String,
+        snippet: String,
+    },
+    /// The `robots.txt` of the website disallows the URL.
+    DisallowedByRobots {
+        url: String,
+    },
+    /// ffmpeg failed to download the video.
+    Ffmpeg {
+        message: String,
+    },
+    /// The server answered with a status other than success.
+    HttpStatus {
+        status: u16,
+        url: String,
+    },
     NoHost,
+    /// The page has no JSON-LD, microdata or RDFa.
+    NoStructuredData,
+    /// The page has structured data, but none of it describes a recipe.
+    NotARecipe,
+    /// The video has no HTTP or HTTPS URL to a video file that could be downloaded.
+    NoVideoFile,
     UnknownWebsite,
 
     // Externals
@@ -14,9 +49,13 @@ pub enum Error {
     Select(String),
 
     #[from]
-    Deserialize(serde_json::Error),
+    Io(std::io::Error),
+    #[from]
+    Json(serde_json::Error),
     #[from]
     Request(reqwest::Error),
+    #[from]
+    Toml(toml::de::Error),
 }
 
 impl core::fmt::Display for Error {
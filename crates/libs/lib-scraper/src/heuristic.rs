@@ -0,0 +1,170 @@
+//! Guessing the recipe of a page without a recipe in its structured data from its layout.
+//!
+//! The ingredients are the list whose items mostly start with a quantity, and the instructions
+//! are the numbered list, or the numbered paragraphs, with the most text. The name and the
+//! image come from the Open Graph tags, falling back to the first `<h1>` for the name.
+
+use regex::Regex;
+use scraper::{ElementRef, Html, Selector};
+use std::sync::OnceLock;
+use url::Url;
+
+use crate::{
+    custom::element_text,
+    ingredients::parse_ingredient,
+    meta,
+    schema::{
+        common::{CreativeWorkOrItemListOrText, HowTo, ImageObjectOrUrl},
+        recipe::RecipeSchema,
+        AtType,
+    },
+    Result,
+};
+
+/// The confidence below which a guess is rejected, unless configured otherwise. A guess
+/// needs a list of instructions besides the ingredients to reach it.
+pub(crate) const DEFAULT_MIN_CONFIDENCE: f32 = 0.75;
+
+/// The share of the items of a list that must look like ingredients for the list to be
+/// taken as the ingredients.
+const MIN_INGREDIENT_RATIO: f32 = 0.6;
+
+/// The longest ingredient line, in characters. Longer items are sentences.
+const MAX_INGREDIENT_LEN: usize = 120;
+
+/// The average length, in characters, above which a list holds sentences rather than items.
+const MIN_STEP_LEN: usize = 25;
+
+/// A recipe guessed from the layout of a page.
+pub(crate) struct Guess {
+    pub(crate) recipe: RecipeSchema,
+
+    /// How likely the page is a recipe read correctly, from 0 to 1.
+    pub(crate) confidence: f32,
+}
+
+/// Guesses the recipe of the page. Returns `None` when no list of ingredients is found.
+pub(crate) fn extract(doc: &Html, url: &str) -> Result<Option<Guess>> {
+    let lists: Vec<(ElementRef, Vec<String>)> = doc
+        .select(&Selector::parse("ul, ol")?)
+        .map(|list| (list, list_items(list)))
+        .filter(|(_, items)| items.len() >= 2)
+        .collect();
+
+    let Some((ingredients_list, ingredients, ratio)) = lists
+        .iter()
+        .map(|(list, items)| (list, items, ingredient_ratio(items)))
+        .filter(|(_, _, ratio)| *ratio >= MIN_INGREDIENT_RATIO)
+        .max_by(|(_, a, ra), (_, b, rb)| ra.total_cmp(rb).then(a.len().cmp(&b.len())))
+    else {
+        return Ok(None);
+    };
+
+    let steps = match lists
+        .iter()
+        .filter(|(list, items)| {
+            list.id() != ingredients_list.id()
+                && list.value().name() == "ol"
+                && average_len(items) >= MIN_STEP_LEN
+        })
+        .map(|(_, items)| items.clone())
+        .max_by_key(|items| items.iter().map(String::len).sum::<usize>())
+    {
+        Some(steps) => Some(steps),
+        None => numbered_paragraphs(doc)?,
+    };
+
+    let base = Url::parse(url).ok();
+    let name = match meta::content(doc, "og:title")? {
+        Some(title) => Some(title),
+        None => doc
+            .select(&Selector::parse("h1")?)
+            .map(element_text)
+            .find(|text| !text.is_empty()),
+    };
+    let image = meta::content(doc, "og:image")?
+        .and_then(|src| meta::resolve(&base, &src))
+        .map(ImageObjectOrUrl::Url);
+
+    let mut confidence = 0.5 * ratio;
+    if steps.is_some() {
+        confidence += 0.3;
+    }
+    if name.is_some() {
+        confidence += 0.1;
+    }
+    if image.is_some() {
+        confidence += 0.1;
+    }
+
+    let recipe = RecipeSchema {
+        at_type: Some(AtType::Recipe),
+        name,
+        image,
+        recipe_ingredient: Some(ingredients.clone()),
+        recipe_instructions: steps.map(|steps| {
+            CreativeWorkOrItemListOrText::ItemList(
+                steps
+                    .into_iter()
+                    .map(|text| HowTo {
+                        at_type: AtType::HowToStep,
+                        text,
+                        ..Default::default()
+                    })
+                    .collect(),
+            )
+        }),
+        ..Default::default()
+    };
+
+    Ok(Some(Guess {
+        recipe,
+        confidence: confidence.min(1.0),
+    }))
+}
+
+/// The text of the items of the list, without its nested lists' items.
+fn list_items(list: ElementRef) -> Vec<String> {
+    list.children()
+        .filter_map(ElementRef::wrap)
+        .filter(|item| item.value().name() == "li")
+        .map(element_text)
+        .filter(|text| !text.is_empty())
+        .collect()
+}
+
+/// The share of the items that start with a quantity, name something and are short enough to
+/// be ingredients. Items such as "2019 (12)" in the archive of a blog name nothing.
+fn ingredient_ratio(items: &[String]) -> f32 {
+    let count = items
+        .iter()
+        .filter(|item| {
+            item.chars().count() <= MAX_INGREDIENT_LEN
+                && item.chars().any(char::is_alphabetic)
+                && parse_ingredient(item).quantity.is_some()
+        })
+        .count();
+    count as f32 / items.len() as f32
+}
+
+fn average_len(items: &[String]) -> usize {
+    items.iter().map(String::len).sum::<usize>() / items.len().max(1)
+}
+
+/// The paragraphs numbered like "1.", "2)" or "Step 3:", when there are at least two of them.
+fn numbered_paragraphs(doc: &Html) -> Result<Option<Vec<String>>> {
+    static INSTANCE: OnceLock<Regex> = OnceLock::new();
+    let re = INSTANCE.get_or_init(|| Regex::new(r"(?i)^(?:step\s*)?\d+\s*[.):]\s*").unwrap());
+
+    let steps: Vec<String> = doc
+        .select(&Selector::parse("p")?)
+        .map(element_text)
+        .filter_map(|text| {
+            let number = re.find(&text)?;
+            let step = text[number.end()..].trim();
+            (!step.is_empty()).then(|| step.to_string())
+        })
+        .collect();
+
+    Ok((steps.len() >= 2).then_some(steps))
+}
@@ -0,0 +1,241 @@
+use reqwest::{
+    header::{self, HeaderMap},
+    StatusCode,
+};
+use scraper::{Html, Selector};
+use std::{sync::OnceLock, time::Duration};
+use tokio::runtime::Runtime;
+
+use crate::{
+    cache::{Fetched, Validators},
+    config::ScraperConfig,
+    websites::Website,
+    Error, HttpClient, Result,
+};
+
+/// The [`HttpClient`] that sends the requests to the websites.
+pub(crate) struct AppHttpClient {
+    config: ScraperConfig,
+    client: reqwest::Client,
+    runtime: OnceLock<Runtime>,
+}
+
+impl AppHttpClient {
+    pub(crate) fn new(config: ScraperConfig) -> Result<Self> {
+        let mut builder = reqwest::Client::builder()
+            .user_agent(&config.user_agent)
+            .connect_timeout(config.connect_timeout)
+            .read_timeout(config.read_timeout);
+        if let Some(proxy) = &config.proxy {
+            builder = builder.proxy(reqwest::Proxy::all(proxy)?);
+        }
+
+        Ok(Self {
+            client: builder.build()?,
+            runtime: OnceLock::new(),
+            config,
+        })
+    }
+
+    /// The runtime the blocking requests are sent from. It is only created when first needed
+    /// rather than when the scraper is initialized from within an async context.
+    ///
+    /// The blocking requests go through the same client as the async ones, so that both have
+    /// the same timeouts. Its worker keeps the connections they open alive for the async
+    /// requests that reuse them.
+    fn runtime(&self) -> Result<&Runtime> {
+        if let Some(runtime) = self.runtime.get() {
+            return Ok(runtime);
+        }
+
+        let runtime = tokio::runtime::Builder::new_multi_thread()
+            .worker_threads(1)
+            .enable_all()
+            .build()?;
+        Ok(self.runtime.get_or_init(|| runtime))
+    }
+
+    fn can_retry(&self, attempt: u32, error: &reqwest::Error) -> bool {
+        attempt < self.config.max_retries && (error.is_timeout() || error.is_connect())
+    }
+
+    fn retry_delay(
+        &self,
+        attempt: u32,
+        status: StatusCode,
+        headers: &HeaderMap,
+    ) -> Option<Duration> {
+        let is_retryable = status == StatusCode::TOO_MANY_REQUESTS || status.is_server_error();
+        if !is_retryable || attempt >= self.config.max_retries {
+            return None;
+        }
+
+        // The website must not be asked again before it allows it, so the request gives up
+        // rather than shortens a delay longer than the maximum backoff.
+        match retry_after(headers) {
+            Some(delay) => (delay <= self.config.max_backoff).then_some(delay),
+            None => Some(self.config.backoff_delay(attempt)),
+        }
+    }
+
+    fn check_size(&self, len: usize) -> Result<()> {
+        if len > self.config.max_body_size {
+            return Err(Error::BodyTooLarge {
+                limit: self.config.max_body_size,
+            });
+        }
+        Ok(())
+    }
+}
+
+#[async_trait::async_trait]
+impl HttpClient for AppHttpClient {
+    async fn get_async<'a>(&'a self, host: Website, url: &str) -> Result<String> {
+        let fetched = self
+            .get_conditional_async(host, url, &Validators::default())
+            .await?;
+        into_body(fetched, url)
+    }
+
+    fn get(&self, host: Website, url: &str) -> Result<String> {
+        let fetched = self.get_conditional(host, url, &Validators::default())?;
+        into_body(fetched, url)
+    }
+
+    async fn get_conditional_async<'a>(
+        &'a self,
+        _host: Website,
+        url: &str,
+        validators: &Validators,
+    ) -> Result<Fetched> {
+        let mut attempt = 0;
+        loop {
+            let req = with_validators(self.client.get(url), validators);
+            let mut res = match req.send().await {
+                Ok(res) => res,
+                Err(error) if self.can_retry(attempt, &error) => {
+                    tokio::time::sleep(self.config.backoff_delay(attempt)).await;
+                    attempt += 1;
+                    continue;
+                }
+                Err(error) => return Err(error.into()),
+            };
+
+            let status = res.status();
+            if let Some(delay) = self.retry_delay(attempt, status, res.headers()) {
+                tokio::time::sleep(delay).await;
+                attempt += 1;
+                continue;
+            }
+            if status == StatusCode::NOT_MODIFIED {
+                return Ok(Fetched::NotModified);
+            }
+
+            let validators = read_validators(res.headers());
+            self.check_size(res.content_length().unwrap_or_default() as usize)?;
+            let mut body = Vec::new();
+            while let Some(chunk) = res.chunk().await? {
+                body.extend_from_slice(&chunk);
+                self.check_size(body.len())?;
+            }
+            let body = check_status(status, url, String::from_utf8_lossy(&body).into_owned())?;
+            return Ok(Fetched::Body { body, validators });
+        }
+    }
+
+    fn get_conditional(
+        &self,
+        host: Website,
+        url: &str,
+        validators: &Validators,
+    ) -> Result<Fetched> {
+        self.runtime()?
+            .block_on(self.get_conditional_async(host, url, validators))
+    }
+}
+
+fn with_validators(
+    mut req: reqwest::RequestBuilder,
+    validators: &Validators,
+) -> reqwest::RequestBuilder {
+    if let Some(etag) = &validators.etag {
+        req = req.header(header::IF_NONE_MATCH, etag);
+    }
+    if let Some(last_modified) = &validators.last_modified {
+        req = req.header(header::IF_MODIFIED_SINCE, last_modified);
+    }
+    req
+}
+
+fn read_validators(headers: &HeaderMap) -> Validators {
+    let get = |name| {
+        headers
+            .get(name)
+            .and_then(|value| value.to_str().ok())
+            .map(String::from)
+    };
+    Validators {
+        etag: get(header::ETAG),
+        last_modified: get(header::LAST_MODIFIED),
+    }
+}
+
+/// A 304 only answers a conditional request, so it is an error for an unconditional one.
+fn into_body(fetched: Fetched, url: &str) -> Result<String> {
+    match fetched {
+        Fetched::Body { body, .. } => Ok(body),
+        Fetched::NotModified => Err(Error::HttpStatus {
+            status: StatusCode::NOT_MODIFIED.as_u16(),
+            url: url.to_string(),
+        }),
+    }
+}
+
+/// Reads the `Retry-After` header. Only the delay in seconds is supported; the HTTP date
+/// form falls back to the exponential backoff.
+fn retry_after(headers: &HeaderMap) -> Option<Duration> {
+    headers
+        .get(header::RETRY_AFTER)?
+        .to_str()
+        .ok()?
+        .trim()
+        .parse()
+        .ok()
+        .map(Duration::from_secs)
+}
+
+fn check_status(status: StatusCode, url: &str, body: String) -> Result<String> {
+    if status.is_success() {
+        Ok(body)
+    } else if is_blocked(&Html::parse_document(&body)) {
+        Err(Error::Blocked)
+    } else {
+        Err(Error::HttpStatus {
+            status: status.as_u16(),
+            url: url.to_string(),
+        })
+    }
+}
+
+/// Whether the page is a captcha or a bot challenge rather than the requested page.
+pub(crate) fn is_blocked(doc: &Html) -> bool {
+    const TITLES: [&str; 5] = [
+        "access denied",
+        "are you a robot",
+        "attention required",
+        "captcha",
+        "just a moment",
+    ];
+    const SELECTORS: &str = "#challenge-form, #cf-challenge-running, #px-captcha, .g-recaptcha, .h-captcha, [data-sitekey]";
+
+    let title = Selector::parse("title")
+        .ok()
+        .and_then(|sel| doc.select(&sel).next())
+        .map(|el| el.text().collect::<String>().to_lowercase())
+        .unwrap_or_default();
+    if TITLES.iter().any(|t| title.contains(t)) {
+        return true;
+    }
+
+    Selector::parse(SELECTORS).is_ok_and(|sel| doc.select(&sel).next().is_some())
+}
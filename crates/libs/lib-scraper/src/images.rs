@@ -0,0 +1,281 @@
+//! Selection of the best image of a recipe among the ones it gives.
+//!
+//! Recipes often give the same picture cropped to several aspect ratios, along with thumbnails.
+//! The candidates are ranked by their number of pixels, lowered for aspect ratios outside of
+//! square to widescreen. The size comes from the `width` and `height` of an `ImageObject`, or
+//! from the URL, e.g. `photo-1200x800.jpg` or `photo.jpg?w=1200&h=800`. Thumbnails rank
+//! below the images of the recipe.
+
+use regex::Regex;
+use serde_json::{Map, Value};
+use std::{borrow::Cow, sync::OnceLock};
+use url::Url;
+
+use crate::schema::{
+    common::{DistanceOrQuantitativeValue, ImageObjectOrUrl, ImageObjectType},
+    recipe::RecipeSchema,
+};
+
+/// The widest aspect ratio that does not lower the rank of an image.
+const MAX_ASPECT_RATIO: f64 = 16.0 / 9.0;
+
+/// The images of a recipe, best first.
+#[derive(Clone, Debug, PartialEq)]
+pub struct RankedImages {
+    /// The image to show for the recipe.
+    pub best: Url,
+
+    /// The other images, from best to worst, e.g. for the additional images of the recipe.
+    pub alternates: Vec<Url>,
+}
+
+/// An image of the recipe and its size, when known.
+struct Candidate {
+    url: Url,
+    size: Option<(u32, u32)>,
+    thumbnail: bool,
+
+    /// Whether the image is the original of resized copies found among the candidates.
+    original: bool,
+}
+
+impl Candidate {
+    fn new(url: Url, size: Option<(u32, u32)>) -> Self {
+        let size = size.or_else(|| size_from_url(&url));
+        Self {
+            url,
+            size,
+            thumbnail: false,
+            original: false,
+        }
+    }
+
+    /// The number of pixels, lowered for portrait and panoramic images.
+    fn score(&self) -> Option<f64> {
+        let (width, height) = self.size?;
+        let (width, height) = (f64::from(width), f64::from(height));
+        let ratio = width / height;
+
+        // Portrait images are cropped the most in the landscape frames they are shown in.
+        let factor = if ratio < 1.0 {
+            ratio * ratio
+        } else if ratio > MAX_ASPECT_RATIO {
+            MAX_ASPECT_RATIO / ratio
+        } else {
+            1.0
+        };
+        Some(width * height * factor)
+    }
+}
+
+impl RecipeSchema {
+    /// Ranks the images of the recipe, then its thumbnails.
+    ///
+    /// An image of unknown size whose resized copies are also given, e.g. `photo.jpg` along
+    /// with `photo-500x500.jpg`, is taken to be the original and ranks above them. The other
+    /// images of unknown size rank below those of known size, in the order the recipe gives
+    /// them. Returns `None` when the recipe has no image.
+    pub fn ranked_images(&self) -> Option<RankedImages> {
+        let mut candidates = Vec::new();
+        if let Some(image) = &self.image {
+            collect(image, &mut candidates);
+        }
+        let images = candidates.len();
+        if let Some(thumbnail) = &self.thumbnail {
+            collect_object(thumbnail, &mut candidates);
+        }
+        if let Some(url) = &self.thumbnail_url {
+            candidates.push(Candidate::new(url.clone(), None));
+        }
+        for candidate in &mut candidates[images..] {
+            candidate.thumbnail = true;
+        }
+
+        let mut seen = Vec::new();
+        candidates.retain(|candidate| {
+            let is_new = !seen.contains(&candidate.url);
+            seen.push(candidate.url.clone());
+            is_new
+        });
+
+        // An original is at least as large as its largest copy.
+        let copies: Vec<(Url, (u32, u32))> = candidates
+            .iter()
+            .filter_map(|candidate| Some((original_of(&candidate.url)?, candidate.size?)))
+            .collect();
+        for candidate in candidates.iter_mut().filter(|c| c.size.is_none()) {
+            candidate.size = copies
+                .iter()
+                .filter(|(original, _)| *original == candidate.url)
+                .map(|&(_, size)| size)
+                .max_by_key(|&(width, height)| u64::from(width) * u64::from(height));
+            candidate.original = candidate.size.is_some();
+        }
+
+        // The sort is stable, which keeps the images of unknown size in their order.
+        candidates.sort_by(|a, b| {
+            a.thumbnail
+                .cmp(&b.thumbnail)
+                .then_with(|| match (a.score(), b.score()) {
+                    (Some(a), Some(b)) => b.total_cmp(&a),
+                    (a, b) => b.is_some().cmp(&a.is_some()),
+                })
+                .then_with(|| b.original.cmp(&a.original))
+        });
+
+        let mut urls = candidates.into_iter().map(|candidate| candidate.url);
+        Some(RankedImages {
+            best: urls.next()?,
+            alternates: urls.collect(),
+        })
+    }
+}
+
+fn collect(image: &ImageObjectOrUrl, candidates: &mut Vec<Candidate>) {
+    match image {
+        ImageObjectOrUrl::Url(url) => candidates.push(Candidate::new(url.clone(), None)),
+        ImageObjectOrUrl::ImageObject(object) => collect_object(object, candidates),
+        ImageObjectOrUrl::Images(images) => {
+            for image in images {
+                collect(image, candidates);
+            }
+        }
+    }
+}
+
+fn collect_object(object: &ImageObjectType, candidates: &mut Vec<Candidate>) {
+    let size = object
+        .width
+        .as_ref()
+        .and_then(pixels)
+        .zip(object.height.as_ref().and_then(pixels));
+
+    if let Some(url) = object.content_url.as_ref().or(object.url.as_ref()) {
+        candidates.push(Candidate::new(url.clone(), size));
+    }
+}
+
+/// The number of pixels of a width or height, e.g. 1200 in "1200 px".
+fn pixels(value: &DistanceOrQuantitativeValue) -> Option<u32> {
+    match value {
+        DistanceOrQuantitativeValue::Distance(distance) => {
+            let digits: String = distance
+                .value
+                .trim()
+                .chars()
+                .take_while(char::is_ascii_digit)
+                .collect();
+            digits.parse().ok()
+        }
+        DistanceOrQuantitativeValue::QuantitativeValue(quantity) => {
+            u32::try_from(quantity.value).ok()
+        }
+    }
+    .filter(|&n| n > 0)
+}
+
+/// The pattern of the size written in the path of an image, e.g. `-1200x800.jpg`.
+fn sized_path() -> &'static Regex {
+    static INSTANCE: OnceLock<Regex> = OnceLock::new();
+    INSTANCE.get_or_init(|| Regex::new(r"[-_](\d{2,5})x(\d{2,5})(\.\w+)$").unwrap())
+}
+
+/// The size written in the URL of the image, e.g. `photo-1200x800.jpg` or
+/// `photo.jpg?w=1200&h=800`.
+fn size_from_url(url: &Url) -> Option<(u32, u32)> {
+    if let Some(caps) = sized_path().captures(url.path()) {
+        return caps[1].parse().ok().zip(caps[2].parse().ok());
+    }
+
+    let param = |names: [&str; 2]| {
+        url.query_pairs()
+            .find(|(key, _)| names.contains(&key.as_ref()))
+            .and_then(|(_, value)| value.parse::<u32>().ok())
+            .filter(|&n| n > 0)
+    };
+    param(["w", "width"]).zip(param(["h", "height"]))
+}
+
+/// The URL of the image a resized copy was made from, e.g. `photo.jpg` for
+/// `photo-500x500.jpg`.
+fn original_of(url: &Url) -> Option<Url> {
+    let caps = sized_path().captures(url.path())?;
+    let path = format!("{}{}", &url.path()[..caps.get(0)?.start()], &caps[3]);
+
+    let mut original = url.clone();
+    original.set_path(&path);
+    Some(original)
+}
+
+/// Resolves the relative URLs of the images in the structured data against the URL of the
+/// page, e.g. `/uploads/soup.jpg`. The JSON is returned as is when it has none.
+pub(crate) fn resolve_relative_urls<'a>(json: &'a str, page_url: &str) -> Cow<'a, str> {
+    let Ok(base) = Url::parse(page_url) else {
+        return Cow::Borrowed(json);
+    };
+    let Ok(mut value) = serde_json::from_str::<Value>(json) else {
+        return Cow::Borrowed(json);
+    };
+
+    if resolve_value(&mut value, &base) {
+        Cow::Owned(value.to_string())
+    } else {
+        Cow::Borrowed(json)
+    }
+}
+
+/// Resolves the URLs of the images found in the value. Returns whether any was relative.
+fn resolve_value(value: &mut Value, base: &Url) -> bool {
+    match value {
+        Value::Array(values) => values.iter_mut().fold(false, |resolved, value| {
+            resolve_value(value, base) | resolved
+        }),
+        Value::Object(map) => resolve_object(map, base),
+        _ => false,
+    }
+}
+
+fn resolve_object(map: &mut Map<String, Value>, base: &Url) -> bool {
+    let is_image = map
+        .get("@type")
+        .and_then(Value::as_str)
+        .is_some_and(|t| t == "ImageObject");
+
+    let mut resolved = false;
+    for (key, value) in map.iter_mut() {
+        let holds_urls = match key.as_str() {
+            "image" | "thumbnailUrl" => true,
+            "url" | "contentUrl" => is_image,
+            _ => false,
+        };
+
+        resolved |= match value {
+            Value::String(s) if holds_urls => resolve_string(s, base),
+            Value::Array(values) if holds_urls => {
+                values.iter_mut().fold(false, |resolved, value| {
+                    let is_resolved = match value {
+                        Value::String(s) => resolve_string(s, base),
+                        value => resolve_value(value, base),
+                    };
+                    is_resolved | resolved
+                })
+            }
+            value => resolve_value(value, base),
+        };
+    }
+    resolved
+}
+
+fn resolve_string(s: &mut String, base: &Url) -> bool {
+    if s.is_empty() || Url::parse(s).is_ok() {
+        return false;
+    }
+
+    match base.join(s) {
+        Ok(url) => {
+            *s = url.to_string();
+            true
+        }
+        Err(_) => false,
+    }
+}
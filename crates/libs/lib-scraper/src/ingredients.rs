@@ -0,0 +1,367 @@
+//! Parsing of free-form ingredient lines such as "1 1/2 cups all-purpose flour, sifted".
+
+use regex::Regex;
+use std::{fmt::Formatter, sync::OnceLock};
+
+use crate::schema::recipe::RecipeSchema;
+
+/// An ingredient line broken down into its parts.
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct ParsedIngredient {
+    /// The amount of the ingredient, e.g. `1.5` in "1 1/2 cups flour".
+    pub quantity: Option<Quantity>,
+
+    /// The unit of the quantity, e.g. cups in "1 1/2 cups flour".
+    pub unit: Option<Unit>,
+
+    /// The ingredient itself, e.g. "all-purpose flour".
+    pub name: String,
+
+    /// How the ingredient is prepared or any other note, e.g. "sifted" or "optional".
+    pub preparation: Option<String>,
+
+    /// Ingredients that can be used instead, e.g. margarine in "1 cup butter or margarine".
+    pub alternatives: Vec<ParsedIngredient>,
+}
+
+/// The amount of an ingredient.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum Quantity {
+    Amount(f64),
+    Range(f64, f64),
+}
+
+impl Quantity {
+    /// Multiplies the quantity by the factor, e.g. to double a recipe.
+    pub fn scale(&self, factor: f64) -> Self {
+        match self {
+            Quantity::Amount(v) => Quantity::Amount(v * factor),
+            Quantity::Range(min, max) => Quantity::Range(min * factor, max * factor),
+        }
+    }
+}
+
+/// A unit of measure found in ingredient lines.
+#[derive(Clone, Copy, Debug, Eq, Hash, PartialEq)]
+pub enum Unit {
+    Bunch,
+    Can,
+    Centiliter,
+    Clove,
+    Cup,
+    Dash,
+    Deciliter,
+    FluidOunce,
+    Gallon,
+    Gram,
+    Handful,
+    Kilogram,
+    Liter,
+    Milligram,
+    Milliliter,
+    Ounce,
+    Package,
+    Piece,
+    Pinch,
+    Pint,
+    Pound,
+    Quart,
+    Slice,
+    Sprig,
+    Stick,
+    Tablespoon,
+    Teaspoon,
+}
+
+impl Unit {
+    /// Matches a unit from its name, plural or abbreviation.
+    ///
+    /// The single letters `T` and `t` stand for tablespoon and teaspoon, so the match is
+    /// case-sensitive for those.
+    pub fn parse(s: &str) -> Option<Self> {
+        use Unit::*;
+
+        let s = s.trim().trim_end_matches('.');
+        match s {
+            "T" | "Tb" | "Tbs" => return Some(Tablespoon),
+            "t" => return Some(Teaspoon),
+            _ => {}
+        }
+
+        let unit = match s.to_lowercase().as_str() {
+            "bunch" | "bunches" => Bunch,
+            "can" | "cans" | "tin" | "tins" => Can,
+            "cl" | "centiliter" | "centiliters" | "centilitre" | "centilitres" => Centiliter,
+            "clove" | "cloves" => Clove,
+            "c" | "cup" | "cups" => Cup,
+            "dash" | "dashes" => Dash,
+            "dl" | "deciliter" | "deciliters" | "decilitre" | "decilitres" => Deciliter,
+            "fl oz" | "fl. oz" | "floz" | "fluid ounce" | "fluid ounces" => FluidOunce,
+            "gal" | "gallon" | "gallons" => Gallon,
+            "g" | "gr" | "gram" | "grams" | "gramme" | "grammes" => Gram,
+            "handful" | "handfuls" => Handful,
+            "kg" | "kgs" | "kilogram" | "kilograms" | "kilogramme" | "kilogrammes" => Kilogram,
+            "l" | "liter" | "liters" | "litre" | "litres" => Liter,
+            "mg" | "milligram" | "milligrams" | "milligramme" | "milligrammes" => Milligram,
+            "ml" | "milliliter" | "milliliters" | "millilitre" | "millilitres" => Milliliter,
+            "oz" | "ounce" | "ounces" => Ounce,
+            "package" | "packages" | "pkg" | "pkgs" | "packet" | "packets" => Package,
+            "piece" | "pieces" | "pc" | "pcs" => Piece,
+            "pinch" | "pinches" => Pinch,
+            "pt" | "pint" | "pints" => Pint,
+            "lb" | "lbs" | "pound" | "pounds" => Pound,
+            "qt" | "quart" | "quarts" => Quart,
+            "slice" | "slices" => Slice,
+            "sprig" | "sprigs" => Sprig,
+            "stick" | "sticks" => Stick,
+            "tbsp" | "tbsps" | "tbs" | "tbl" | "tablespoon" | "tablespoons" => Tablespoon,
+            "tsp" | "tsps" | "teaspoon" | "teaspoons" => Teaspoon,
+            _ => return None,
+        };
+        Some(unit)
+    }
+}
+
+impl std::fmt::Display for Unit {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        use Unit::*;
+
+        let s = match self {
+            Bunch => "bunch",
+            Can => "can",
+            Centiliter => "cl",
+            Clove => "clove",
+            Cup => "cup",
+            Dash => "dash",
+            Deciliter => "dl",
+            FluidOunce => "fl oz",
+            Gallon => "gal",
+            Gram => "g",
+            Handful => "handful",
+            Kilogram => "kg",
+            Liter => "l",
+            Milligram => "mg",
+            Milliliter => "ml",
+            Ounce => "oz",
+            Package => "package",
+            Piece => "piece",
+            Pinch => "pinch",
+            Pint => "pt",
+            Pound => "lb",
+            Quart => "qt",
+            Slice => "slice",
+            Sprig => "sprig",
+            Stick => "stick",
+            Tablespoon => "tbsp",
+            Teaspoon => "tsp",
+        };
+        write!(f, "{s}")
+    }
+}
+
+impl RecipeSchema {
+    /// Parses every line of [`RecipeSchema::recipe_ingredient`].
+    pub fn parsed_ingredients(&self) -> Vec<ParsedIngredient> {
+        self.recipe_ingredient
+            .iter()
+            .flatten()
+            .map(|line| parse_ingredient(line))
+            .collect()
+    }
+}
+
+/// Parses an ingredient line.
+///
+/// Parsing never fails: whatever cannot be recognized as a quantity or a unit ends up in the
+/// name of the ingredient.
+pub fn parse_ingredient(line: &str) -> ParsedIngredient {
+    let line = replace_unicode_fractions(line);
+    let line = line.split_whitespace().collect::<Vec<_>>().join(" ");
+
+    let (quantity, rest) = parse_quantity(&line);
+    let (rest, mut notes) = take_parenthesized(rest);
+    let (unit, rest) = match quantity {
+        Some(_) => parse_unit(&rest),
+        None => (None, rest.as_str()),
+    };
+    let rest = rest.strip_prefix("of ").unwrap_or(rest);
+
+    let (main, preparation) = match rest.split_once(',') {
+        Some((main, preparation)) => (main.trim().to_string(), Some(preparation.trim())),
+        None => (rest.trim().to_string(), None),
+    };
+    if let Some(preparation) = preparation.filter(|p| !p.is_empty()) {
+        notes.insert(0, preparation.to_string());
+    }
+
+    let mut parts = or_regex().split(&main);
+    let name = parts.next().unwrap_or_default().trim().to_string();
+    let alternatives = parts
+        .map(str::trim)
+        .filter(|part| !part.is_empty())
+        .map(parse_ingredient)
+        .collect();
+
+    ParsedIngredient {
+        quantity,
+        unit,
+        name,
+        preparation: (!notes.is_empty()).then(|| notes.join(", ")),
+        alternatives,
+    }
+}
+
+fn quantity_regex() -> &'static Regex {
+    static INSTANCE: OnceLock<Regex> = OnceLock::new();
+
+    INSTANCE.get_or_init(|| {
+        let number = r"\d+\s+\d+/\d+|\d+/\d+|\d{1,3}(?:,\d{3})+(?:\.\d+)?|\d+(?:[.,]\d+)?";
+        Regex::new(&format!(
+            r"^(?<min>{number})(?:\s*(?:-|–|—|to|or)\s*(?<max>{number}))?\s*"
+        ))
+        .unwrap()
+    })
+}
+
+fn or_regex() -> &'static Regex {
+    static INSTANCE: OnceLock<Regex> = OnceLock::new();
+
+    INSTANCE.get_or_init(|| Regex::new(r"\s+or\s+").unwrap())
+}
+
+fn parse_quantity(line: &str) -> (Option<Quantity>, &str) {
+    let Some(caps) = quantity_regex().captures(line) else {
+        return (None, line);
+    };
+
+    let rest = &line[caps[0].len()..];
+    let Some(min) = parse_number(&caps["min"]) else {
+        return (None, line);
+    };
+
+    let quantity = match caps.name("max").and_then(|max| parse_number(max.as_str())) {
+        Some(max) if max > min => Quantity::Range(min, max),
+        Some(_) | None => Quantity::Amount(min),
+    };
+    (Some(quantity), rest)
+}
+
+fn parse_number(s: &str) -> Option<f64> {
+    static THOUSANDS: OnceLock<Regex> = OnceLock::new();
+
+    let s = s.trim();
+
+    if let Some((whole, fraction)) = s.split_once(char::is_whitespace) {
+        return Some(parse_number(whole)? + parse_number(fraction)?);
+    }
+
+    if let Some((num, den)) = s.split_once('/') {
+        let num: f64 = num.parse().ok()?;
+        let den: f64 = den.parse().ok()?;
+        return (den != 0.0).then(|| num / den);
+    }
+
+    // A comma followed by groups of three digits separates the thousands, e.g. "1,000".
+    let thousands =
+        THOUSANDS.get_or_init(|| Regex::new(r"^\d{1,3}(?:,\d{3})+(?:\.\d+)?$").unwrap());
+    if thousands.is_match(s) {
+        return s.replace(',', "").parse().ok();
+    }
+
+    s.replace(',', ".").parse().ok()
+}
+
+fn parse_unit(rest: &str) -> (Option<Unit>, &str) {
+    let mut words = rest.splitn(3, ' ');
+    let first = words.next().unwrap_or_default();
+    let second = words.next();
+
+    if let Some(second) = second {
+        let two = format!("{first} {second}");
+        if let Some(unit) = Unit::parse(&two) {
+            return (Some(unit), rest[two.len()..].trim_start());
+        }
+    }
+
+    match Unit::parse(first) {
+        Some(unit) => (Some(unit), rest[first.len()..].trim_start()),
+        None => (None, rest),
+    }
+}
+
+/// Removes the parenthesized parts of the text and returns them as notes.
+fn take_parenthesized(s: &str) -> (String, Vec<String>) {
+    let mut text = String::with_capacity(s.len());
+    let mut notes = Vec::new();
+    let mut note = String::new();
+    let mut depth = 0;
+
+    for c in s.chars() {
+        match c {
+            '(' => {
+                if depth > 0 {
+                    note.push(c);
+                }
+                depth += 1;
+            }
+            ')' if depth > 0 => {
+                depth -= 1;
+                if depth == 0 {
+                    let n = note.trim();
+                    if !n.is_empty() {
+                        notes.push(n.to_string());
+                    }
+                    note.clear();
+                } else {
+                    note.push(c);
+                }
+            }
+            _ if depth > 0 => note.push(c),
+            _ => text.push(c),
+        }
+    }
+
+    let text = text.split_whitespace().collect::<Vec<_>>().join(" ");
+    (text.replace(" ,", ","), notes)
+}
+
+/// Spells out the unicode vulgar fractions, e.g. "1½" becomes "1 1/2".
+pub(crate) fn replace_unicode_fractions(s: &str) -> String {
+    let mut out = String::with_capacity(s.len());
+    for c in s.chars() {
+        let fraction = match c {
+            '½' => "1/2",
+            '⅓' => "1/3",
+            '⅔' => "2/3",
+            '¼' => "1/4",
+            '¾' => "3/4",
+            '⅕' => "1/5",
+            '⅖' => "2/5",
+            '⅗' => "3/5",
+            '⅘' => "4/5",
+            '⅙' => "1/6",
+            '⅚' => "5/6",
+            '⅐' => "1/7",
+            '⅛' => "1/8",
+            '⅜' => "3/8",
+            '⅝' => "5/8",
+            '⅞' => "7/8",
+            '⅑' => "1/9",
+            '⅒' => "1/10",
+            '⁄' => {
+                out.push('/');
+                continue;
+            }
+            _ => {
+                out.push(c);
+                continue;
+            }
+        };
+
+        if out.ends_with(|c: char| c.is_ascii_digit()) {
+            out.push(' ');
+        }
+        out.push_str(fraction);
+    }
+    out
+}
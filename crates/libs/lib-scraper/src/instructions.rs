@@ -0,0 +1,153 @@
+//! Normalization of the recipe instructions into ordered sections of steps.
+
+use regex::Regex;
+use scraper::Html;
+use std::sync::OnceLock;
+use url::Url;
+
+use crate::schema::{
+    common::{CreativeWorkOrItemListOrText, HowTo, ImageObjectOrUrl, TextOrTextObject},
+    recipe::RecipeSchema,
+};
+
+/// A group of steps, e.g. the steps to make the sauce of a pasta recipe.
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct InstructionSection {
+    /// The heading of the section. It is `None` when the recipe does not group its steps.
+    pub name: Option<String>,
+
+    /// The steps of the section, in order.
+    pub steps: Vec<InstructionStep>,
+}
+
+/// A single instruction step.
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct InstructionStep {
+    /// The text of the step, without HTML.
+    pub text: String,
+
+    /// An image illustrating the step.
+    pub image: Option<Url>,
+
+    /// A link to the step on the recipe's page.
+    pub url: Option<Url>,
+}
+
+impl RecipeSchema {
+    /// Flattens [`RecipeSchema::recipe_instructions`] into ordered sections of steps.
+    ///
+    /// Every variant of the instructions is supported. Steps without text once their HTML is
+    /// stripped are skipped, and so are sections without steps.
+    pub fn instruction_sections(&self) -> Vec<InstructionSection> {
+        use CreativeWorkOrItemListOrText::*;
+
+        let sections = match &self.recipe_instructions {
+            None => Vec::new(),
+            Some(Text(text)) => vec![InstructionSection {
+                name: None,
+                steps: split_text(text),
+            }],
+            Some(ItemList(steps)) => vec![InstructionSection {
+                name: None,
+                steps: steps.iter().filter_map(to_step).collect(),
+            }],
+            Some(Sections(sections)) => sections
+                .iter()
+                .map(|section| InstructionSection {
+                    name: section
+                        .name
+                        .as_deref()
+                        .map(strip_html)
+                        .filter(|name| !name.is_empty()),
+                    steps: section
+                        .item_list_element
+                        .iter()
+                        .filter_map(to_step)
+                        .collect(),
+                })
+                .collect(),
+            Some(CreativeWork(work)) => {
+                let image = work.image.as_ref().and_then(image_url);
+                let steps = match &work.description {
+                    Some(TextOrTextObject::Text(text)) => split_text(text),
+                    _ => Vec::new(),
+                }
+                .into_iter()
+                .map(|step| InstructionStep {
+                    image: image.clone(),
+                    ..step
+                })
+                .collect();
+
+                vec![InstructionSection {
+                    name: work.name.as_deref().map(strip_html),
+                    steps,
+                }]
+            }
+        };
+
+        sections
+            .into_iter()
+            .filter(|section| !section.steps.is_empty())
+            .collect()
+    }
+}
+
+fn to_step(how_to: &HowTo) -> Option<InstructionStep> {
+    let text = match strip_html(&how_to.text) {
+        text if text.is_empty() => strip_html(how_to.name.as_deref().unwrap_or_default()),
+        text => text,
+    };
+
+    (!text.is_empty()).then(|| InstructionStep {
+        text,
+        image: how_to.image.as_ref().and_then(image_url),
+        url: how_to.url.clone(),
+    })
+}
+
+fn image_url(image: &ImageObjectOrUrl) -> Option<Url> {
+    match image {
+        ImageObjectOrUrl::Url(url) => Some(url.clone()),
+        ImageObjectOrUrl::ImageObject(object) => {
+            object.url.clone().or_else(|| object.content_url.clone())
+        }
+        ImageObjectOrUrl::Images(images) => images.iter().find_map(image_url),
+    }
+}
+
+/// Splits a block of text into one step per line or HTML paragraph.
+fn split_text(text: &str) -> Vec<InstructionStep> {
+    line_break_regex()
+        .replace_all(text, "\n")
+        .lines()
+        .map(strip_html)
+        .filter(|text| !text.is_empty())
+        .map(|text| InstructionStep {
+            text,
+            ..Default::default()
+        })
+        .collect()
+}
+
+pub(crate) fn line_break_regex() -> &'static Regex {
+    static INSTANCE: OnceLock<Regex> = OnceLock::new();
+
+    INSTANCE.get_or_init(|| Regex::new(r"(?i)<br\s*/?>|</(?:p|li|div|h[1-6])>").unwrap())
+}
+
+/// Removes the tags from the HTML, decodes its entities and collapses its whitespace. The
+/// line breaks and the ends of the blocks become spaces, so that their texts stay apart.
+pub(crate) fn strip_html(s: &str) -> String {
+    if !s.contains(['<', '&']) {
+        return s.split_whitespace().collect::<Vec<_>>().join(" ");
+    }
+
+    Html::parse_fragment(&line_break_regex().replace_all(s, " "))
+        .root_element()
+        .text()
+        .collect::<String>()
+        .split_whitespace()
+        .collect::<Vec<_>>()
+        .join(" ")
+}
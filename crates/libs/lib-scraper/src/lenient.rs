@@ -0,0 +1,92 @@
+use serde::de::DeserializeOwned;
+use serde_json::{Map, Value};
+
+use crate::{
+    report::{FieldError, ScrapeReport},
+    schema::{nutrition::NutritionInformationSchema, recipe::RecipeSchema, AtType},
+};
+
+/// Finds the recipes of an `ld+json` block, dropping the properties that do not deserialize.
+///
+/// The block may be a single object, an array of objects or an object with a `@graph`. Only
+/// the objects whose `@type` is or contains `Recipe` are kept.
+pub(crate) fn parse_recipes(json: &str, report: &mut ScrapeReport) -> Vec<RecipeSchema> {
+    let value: Value = match serde_json::from_str(json) {
+        Ok(value) => value,
+        Err(error) => {
+            report.failed_fields.push(FieldError::new("$", error, json));
+            return Vec::new();
+        }
+    };
+
+    let objects = match value {
+        Value::Array(values) => values,
+        Value::Object(mut map) => match map.remove("@graph") {
+            Some(Value::Array(graph)) => graph,
+            _ => vec![Value::Object(map)],
+        },
+        _ => Vec::new(),
+    };
+
+    objects
+        .into_iter()
+        .filter_map(|value| match value {
+            Value::Object(map) if is_recipe(&map) => Some(map),
+            _ => None,
+        })
+        .map(|mut map| {
+            if let Some(Value::Object(nutrition)) = map.get_mut("nutrition") {
+                retain_valid::<NutritionInformationSchema>(nutrition, "nutrition.", report);
+            }
+            retain_valid::<RecipeSchema>(&mut map, "", report);
+
+            let mut recipe: RecipeSchema =
+                serde_json::from_value(Value::Object(map)).unwrap_or_default();
+            recipe.at_type = Some(AtType::Recipe);
+            recipe
+        })
+        .collect()
+}
+
+/// Whether the object may be a recipe. Like in strict mode, an object without a `@type` is
+/// taken for one.
+fn is_recipe(map: &Map<String, Value>) -> bool {
+    match map.get("@type") {
+        None | Some(Value::Null) => true,
+        Some(Value::String(t)) => t.trim() == "Recipe",
+        Some(Value::Array(types)) => types
+            .iter()
+            .any(|t| t.as_str().is_some_and(|t| t.trim() == "Recipe")),
+        _ => false,
+    }
+}
+
+/// Removes the properties of the object that do not deserialize into `T` on their own and
+/// records them in the report.
+fn retain_valid<T: DeserializeOwned>(
+    map: &mut Map<String, Value>,
+    prefix: &str,
+    report: &mut ScrapeReport,
+) {
+    if serde_json::from_value::<T>(Value::Object(map.clone())).is_ok() {
+        return;
+    }
+
+    map.retain(|key, value| {
+        let single = Map::from_iter([(key.clone(), value.clone())]);
+        match serde_json::from_value::<T>(Value::Object(single)) {
+            Ok(_) => true,
+            Err(error) => {
+                let path = format!("{prefix}{key}");
+                if error.to_string().starts_with("unknown field") {
+                    report.unknown_keys.push(path);
+                } else {
+                    report
+                        .failed_fields
+                        .push(FieldError::new(path, error, &value.to_string()));
+                }
+                false
+            }
+        }
+    });
+}
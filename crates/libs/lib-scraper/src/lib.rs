@@ -1,12 +1,40 @@
+mod cache;
+mod config;
+mod crawler;
 mod custom;
+pub mod duration;
 mod error;
+mod heuristic;
+mod http;
+pub mod images;
+pub mod ingredients;
+pub mod instructions;
+mod lenient;
+mod meta;
+mod microdata;
+mod politeness;
+mod registry;
+mod report;
+mod robots;
 pub mod schema;
+pub mod servings;
+mod text;
+pub mod video;
 mod websites;
 
+use futures::future::join_all;
 use scraper::{Html, Selector};
-use std::sync::{Arc, OnceLock};
+use std::{
+    collections::HashMap,
+    sync::{Arc, OnceLock},
+};
+use tokio::sync::Semaphore;
+use url::Url;
 
 use crate::{
+    cache::{CachedHttpClient, Fetched, Validators},
+    http::AppHttpClient,
+    politeness::Politeness,
     schema::{
         recipe::{GraphObject, RecipeSchema},
         AtType,
@@ -14,100 +42,511 @@ use crate::{
     websites::Website,
 };
 
-pub use self::error::{Error, Result};
+pub use self::{
+    cache::CacheConfig,
+    config::{ScraperConfig, DEFAULT_USER_AGENT},
+    crawler::CrawlConfig,
+    custom::{SelectorExtractor, SiteExtractor},
+    error::{Error, Result},
+    meta::FieldSource,
+    registry::{Registry, WebsiteEntry},
+    report::{FieldError, ParseMode, ScrapeReport},
+};
+
+/// The maximum number of requests [`scrape_many`] sends to a single website at once.
+pub const MAX_CONCURRENT_REQUESTS_PER_HOST: usize = 4;
 
 #[async_trait::async_trait]
 trait HttpClient {
     async fn get_async<'a>(&'a self, host: Website, url: &str) -> Result<String>;
     fn get(&self, host: Website, url: &str) -> Result<String>;
-}
 
-struct AppHttpClient {
-    client: reqwest::Client,
-}
+    /// Requests the page unless it still matches the validators. Clients that do not send
+    /// conditional requests always fetch the page.
+    async fn get_conditional_async<'a>(
+        &'a self,
+        host: Website,
+        url: &str,
+        _validators: &Validators,
+    ) -> Result<Fetched> {
+        let body = self.get_async(host, url).await?;
+        Ok(Fetched::Body {
+            body,
+            validators: Validators::default(),
+        })
+    }
 
-impl AppHttpClient {
-    pub fn new() -> Self {
-        Self {
-            client: reqwest::Client::new(),
-        }
+    fn get_conditional(
+        &self,
+        host: Website,
+        url: &str,
+        _validators: &Validators,
+    ) -> Result<Fetched> {
+        let body = self.get(host, url)?;
+        Ok(Fetched::Body {
+            body,
+            validators: Validators::default(),
+        })
     }
 }
 
-fn scraper() -> &'static Scraper {
-    static INSTANCE: OnceLock<Scraper> = OnceLock::new();
+static SCRAPER: OnceLock<Scraper> = OnceLock::new();
 
-    INSTANCE.get_or_init(|| Scraper {
-        client: Arc::new(AppHttpClient::new()),
+fn scraper() -> &'static Scraper {
+    SCRAPER.get_or_init(|| {
+        Scraper::new(ScraperConfig::default())
+            .unwrap_or_else(|ex| panic!("Fatal - Could not build the scraper: {ex:?}"))
     })
 }
 
+/// Builds the scraper the free functions of this crate use, e.g. to give it a registry of
+/// websites or a cache. Without it, they use a scraper built from [`ScraperConfig::default`].
+///
+/// Fails with [`Error::AlreadyInitialized`] when the scraper is already built, which happens
+/// on the first use of a free function.
+pub fn init(config: ScraperConfig) -> Result<()> {
+    let scraper = Scraper::new(config)?;
+    SCRAPER.set(scraper).map_err(|_| Error::AlreadyInitialized)
+}
+
+/// Scrapes the recipe at the URL, blocking the current thread until done.
+///
+/// Use [`scrape_async`] from within an async runtime.
 pub fn scrape(url: impl Into<String>) -> Result<RecipeSchema> {
     scraper().scrape(&url.into())
 }
 
-#[async_trait::async_trait]
-impl HttpClient for AppHttpClient {
-    async fn get_async<'a>(&'a self, _host: Website, url: &str) -> Result<String> {
-        let res = self.client.get(url).send().await?;
-        let body = res.text().await?;
-        Ok(body)
-    }
+/// Scrapes the recipe at the URL without blocking the runtime.
+pub async fn scrape_async(url: impl Into<String>) -> Result<RecipeSchema> {
+    scraper().scrape_async(&url.into()).await
+}
 
-    fn get(&self, _host: Website, url: &str) -> Result<String> {
-        let client = reqwest::blocking::Client::new();
-        let res = client.get(url).send()?;
-        let body = res.text()?;
-        Ok(body)
-    }
+/// Scrapes the recipe at the URL like [`scrape`] and reports the structured data that had to
+/// be ignored.
+pub fn scrape_with_report(url: impl Into<String>) -> Result<(RecipeSchema, ScrapeReport)> {
+    scraper().scrape_with_report(&url.into())
+}
+
+/// Scrapes the recipe at the URL like [`scrape_async`] and reports the structured data that
+/// had to be ignored.
+pub async fn scrape_async_with_report(
+    url: impl Into<String>,
+) -> Result<(RecipeSchema, ScrapeReport)> {
+    scraper().scrape_async_with_report(&url.into()).await
+}
+
+/// Scrapes every recipe of the page at the URL, blocking the current thread until done.
+///
+/// Use [`scrape_all_async`] from within an async runtime.
+pub fn scrape_all(url: impl Into<String>) -> Result<Vec<RecipeSchema>> {
+    scraper().scrape_all(&url.into())
+}
+
+/// Scrapes every recipe of the page at the URL without blocking the runtime.
+pub async fn scrape_all_async(url: impl Into<String>) -> Result<Vec<RecipeSchema>> {
+    scraper().scrape_all_async(&url.into()).await
+}
+
+/// Extracts the recipe from the HTML of a page without fetching anything, e.g. a saved page.
+///
+/// The URL the page was served from, when known, selects the extraction hints of its website
+/// and resolves the relative links of the page.
+pub fn parse_html(html: &str, source_url: Option<&Url>) -> Result<RecipeSchema> {
+    scraper().parse_html(html, source_url)
+}
+
+/// Scrapes every URL concurrently and returns one result per URL, in the same order.
+///
+/// At most [`MAX_CONCURRENT_REQUESTS_PER_HOST`] requests are in flight for a given website.
+pub async fn scrape_many<I, S>(urls: I) -> Vec<Result<RecipeSchema>>
+where
+    I: IntoIterator<Item = S>,
+    S: Into<String>,
+{
+    let urls: Vec<String> = urls.into_iter().map(Into::into).collect();
+    scraper().scrape_many(&urls).await
 }
 
-struct Scraper {
+/// Finds the recipe pages of the website at the URL, e.g. to import its whole catalogue.
+///
+/// See [`Scraper::discover`].
+pub async fn discover(url: impl Into<String>, config: &CrawlConfig) -> Result<Vec<String>> {
+    scraper().discover(&url.into(), config).await
+}
+
+/// Finds the recipe pages of the website at the URL and scrapes them.
+///
+/// See [`Scraper::crawl`].
+pub async fn crawl(
+    url: impl Into<String>,
+    config: &CrawlConfig,
+) -> Result<Vec<(String, Result<RecipeSchema>)>> {
+    scraper().crawl(&url.into(), config).await
+}
+
+/// Fetches recipe pages and extracts their recipe.
+///
+/// The free functions of this crate use the scraper built by [`init`], or one built from
+/// [`ScraperConfig::default`].
+/// Build your own with [`ScraperConfig::build`] to change its settings.
+pub struct Scraper {
     client: Arc<dyn HttpClient + Sync + Send>,
+    extractors: HashMap<Website, Arc<dyn SiteExtractor>>,
+    mode: ParseMode,
+    politeness: Politeness,
+    unknown_websites: bool,
+    meta_enrichment: bool,
+    text_normalization: bool,
+    min_confidence: f32,
+    registry: Registry,
 }
 
 impl Scraper {
-    fn scrape(&self, url: &str) -> Result<RecipeSchema> {
-        let doc = match Website::from(url) {
-            Ok(host) => {
-                let content = self.client.get(host, url)?;
-                Html::parse_document(&content)
-            }
-            Err(err) => return Err(err),
+    /// Creates a scraper from its settings.
+    pub fn new(config: ScraperConfig) -> Result<Self> {
+        let client: Arc<dyn HttpClient + Sync + Send> = match config.cache.clone() {
+            Some(cache) => Arc::new(CachedHttpClient::new(
+                AppHttpClient::new(config.clone())?,
+                cache,
+            )?),
+            None => Arc::new(AppHttpClient::new(config.clone())?),
+        };
+
+        Ok(Self {
+            mode: config.parse_mode,
+            politeness: Politeness::new(&config),
+            unknown_websites: config.unknown_websites,
+            meta_enrichment: config.meta_enrichment,
+            text_normalization: config.text_normalization,
+            min_confidence: config.min_confidence,
+            client,
+            extractors: custom::builtin_extractors()
+                .into_iter()
+                .chain(config.registry.extractors())
+                .collect(),
+            registry: config.registry,
+        })
+    }
+
+    /// Scrapes the recipe at the URL, blocking the current thread until done.
+    pub fn scrape(&self, url: &str) -> Result<RecipeSchema> {
+        self.scrape_with_report(url).map(|(recipe, _)| recipe)
+    }
+
+    /// Scrapes the recipe at the URL without blocking the runtime.
+    pub async fn scrape_async(&self, url: &str) -> Result<RecipeSchema> {
+        self.scrape_async_with_report(url)
+            .await
+            .map(|(recipe, _)| recipe)
+    }
+
+    /// Scrapes the recipe at the URL like [`Scraper::scrape`] and reports the structured
+    /// data that had to be ignored.
+    pub fn scrape_with_report(&self, url: &str) -> Result<(RecipeSchema, ScrapeReport)> {
+        let (host, content) = self.fetch(url)?;
+        self.extract(Some(&host), url, &content)
+    }
+
+    /// Scrapes the recipe at the URL like [`Scraper::scrape_async`] and reports the
+    /// structured data that had to be ignored.
+    pub async fn scrape_async_with_report(
+        &self,
+        url: &str,
+    ) -> Result<(RecipeSchema, ScrapeReport)> {
+        let (host, content) = self.fetch_async(url).await?;
+        self.extract(Some(&host), url, &content)
+    }
+
+    /// Scrapes every recipe of the page at the URL, e.g. the recipes of a roundup post or a
+    /// menu, blocking the current thread until done.
+    ///
+    /// Recipes without an `@id` are given one made of the URL and their position on the page,
+    /// so that they can be told apart.
+    pub fn scrape_all(&self, url: &str) -> Result<Vec<RecipeSchema>> {
+        let (host, content) = self.fetch(url)?;
+        let (recipes, _) = self.extract_all(Some(&host), url, &content)?;
+        Ok(identify(url, recipes))
+    }
+
+    /// Scrapes every recipe of the page at the URL like [`Scraper::scrape_all`] without
+    /// blocking the runtime.
+    pub async fn scrape_all_async(&self, url: &str) -> Result<Vec<RecipeSchema>> {
+        let (host, content) = self.fetch_async(url).await?;
+        let (recipes, _) = self.extract_all(Some(&host), url, &content)?;
+        Ok(identify(url, recipes))
+    }
+
+    /// Extracts the recipe from the HTML of a page without fetching anything.
+    ///
+    /// Pages from a URL of an unknown website, or without a URL, are parsed from their
+    /// structured data alone.
+    pub fn parse_html(&self, html: &str, source_url: Option<&Url>) -> Result<RecipeSchema> {
+        self.parse_html_with_report(html, source_url)
+            .map(|(recipe, _)| recipe)
+    }
+
+    /// Extracts the recipe like [`Scraper::parse_html`] and reports the structured data that
+    /// had to be ignored.
+    pub fn parse_html_with_report(
+        &self,
+        html: &str,
+        source_url: Option<&Url>,
+    ) -> Result<(RecipeSchema, ScrapeReport)> {
+        let url = source_url.map(Url::as_str).unwrap_or_default();
+        let host = source_url.and_then(|url| {
+            self.registry
+                .website(url.as_str())
+                .or_else(|| Website::from_any(url.as_str()).ok())
+        });
+        self.extract(host.as_ref(), url, html)
+    }
+
+    /// Scrapes every URL concurrently and returns one result per URL, in the same order.
+    ///
+    /// At most [`MAX_CONCURRENT_REQUESTS_PER_HOST`] requests are in flight for a given website.
+    pub async fn scrape_many(&self, urls: &[String]) -> Vec<Result<RecipeSchema>> {
+        let mut semaphores: HashMap<Website, Arc<Semaphore>> = HashMap::new();
+
+        let tasks: Vec<_> = urls
+            .iter()
+            .map(|url| {
+                let semaphore = self.website(url).ok().map(|host| {
+                    semaphores
+                        .entry(host)
+                        .or_insert_with(|| {
+                            Arc::new(Semaphore::new(MAX_CONCURRENT_REQUESTS_PER_HOST))
+                        })
+                        .clone()
+                });
+
+                async move {
+                    let _permit = match &semaphore {
+                        Some(semaphore) => semaphore.acquire().await.ok(),
+                        None => None,
+                    };
+                    self.scrape_async(url).await
+                }
+            })
+            .collect();
+
+        join_all(tasks).await
+    }
+
+    fn website(&self, url: &str) -> Result<Website> {
+        if let Some(website) = self.registry.website(url) {
+            Ok(website)
+        } else if self.unknown_websites {
+            Website::from_any(url)
+        } else {
+            Website::from(url)
+        }
+    }
+
+    fn fetch(&self, url: &str) -> Result<(Website, String)> {
+        let host = self.website(url)?;
+        self.politeness.wait(self.client.as_ref(), &host, url)?;
+        let content = self.client.get(host.clone(), url)?;
+        Ok((host, content))
+    }
+
+    async fn fetch_async(&self, url: &str) -> Result<(Website, String)> {
+        let host = self.website(url)?;
+        self.politeness
+            .wait_async(self.client.as_ref(), &host, url)
+            .await?;
+        let content = self.client.get_async(host.clone(), url).await?;
+        Ok((host, content))
+    }
+
+    /// Extracts the main recipe of the page, which is the first one.
+    fn extract(
+        &self,
+        host: Option<&Website>,
+        url: &str,
+        content: &str,
+    ) -> Result<(RecipeSchema, ScrapeReport)> {
+        let (recipes, report) = self.extract_all(host, url, content)?;
+        let recipe = recipes.into_iter().next().ok_or(Error::NotARecipe)?;
+        Ok((recipe, report))
+    }
+
+    /// Extracts the recipes of the page. There is at least one.
+    ///
+    /// The site extractor and the meta tags of the page only apply to the first recipe, which
+    /// is the one the page is about.
+    fn extract_all(
+        &self,
+        host: Option<&Website>,
+        url: &str,
+        content: &str,
+    ) -> Result<(Vec<RecipeSchema>, ScrapeReport)> {
+        let doc = Html::parse_document(content);
+        let mut report = ScrapeReport {
+            unverified_site: host.is_none_or(Website::is_generic),
+            ..Default::default()
         };
 
         let sel = Selector::parse(r#"script[type='application/ld+json']"#)?;
-        doc.select(&sel)
-            .filter_map(|el| {
-                let json = &el.inner_html();
-                match serde_json::from_str::<RecipeSchema>(json) {
-                    Ok(recipe) => Some(recipe),
-                    Err(error) => {
-                        println!(
-                            "Error while parsing schema: {error}\nURL: {url}\nJSON: {json}\n-----"
-                        );
-                        None
-                    }
+        let has_ld_json = doc.select(&sel).next().is_some();
+        let mut recipes: Vec<RecipeSchema> = doc
+            .select(&sel)
+            .flat_map(|el| {
+                let html = el.inner_html();
+                let json = images::resolve_relative_urls(&html, url);
+                match self.mode {
+                    ParseMode::Lenient => lenient::parse_recipes(&json, &mut report),
+                    ParseMode::Strict => Self::parse_strict(&json, &mut report),
                 }
             })
-            .find_map(|recipe| match recipe.at_graph {
-                None => Some(recipe),
-                Some(graph) => graph.into_iter().find_map(|temp| {
-                    if let GraphObject::Recipe(mut recipe) = temp {
+            .collect();
+
+        if recipes.is_empty() {
+            recipes = microdata::extract(&doc, url)?;
+        }
+
+        match host.and_then(|host| self.extractors.get(host)) {
+            Some(extractor) => {
+                if recipes.is_empty() {
+                    recipes.push(RecipeSchema {
+                        at_type: Some(AtType::Recipe),
+                        ..Default::default()
+                    });
+                }
+                let recipe = &mut recipes[0];
+                extractor.extract(&doc, url, recipe)?;
+
+                if recipe.name.is_none() && recipe.recipe_ingredient.is_none() {
+                    return Err(Self::no_recipe_error(&doc, has_ld_json, report));
+                }
+            }
+            None if recipes.is_empty() => match heuristic::extract(&doc, url)? {
+                Some(guess) if guess.confidence >= self.min_confidence => {
+                    report.confidence = Some(guess.confidence);
+                    recipes.push(guess.recipe);
+                }
+                _ => return Err(Self::no_recipe_error(&doc, has_ld_json, report)),
+            },
+            None => {}
+        }
+
+        if self.meta_enrichment {
+            meta::enrich(&doc, url, &mut recipes[0], &mut report)?;
+        }
+        if self.text_normalization {
+            recipes.iter_mut().for_each(RecipeSchema::normalize_text);
+        }
+        Ok((recipes, report))
+    }
+
+    /// Explains why no recipe could be found in the page.
+    fn no_recipe_error(doc: &Html, has_ld_json: bool, report: ScrapeReport) -> Error {
+        if let Some(field) = report.failed_fields.into_iter().next() {
+            return Error::Deserialize {
+                path: field.path,
+                message: field.message,
+                snippet: field.snippet,
+            };
+        }
+
+        if has_ld_json || microdata::has_items(doc) {
+            Error::NotARecipe
+        } else if http::is_blocked(doc) {
+            Error::Blocked
+        } else {
+            Error::NoStructuredData
+        }
+    }
+
+    fn parse_strict(json: &str, report: &mut ScrapeReport) -> Vec<RecipeSchema> {
+        let de = &mut serde_json::Deserializer::from_str(json);
+        let recipe = match serde_path_to_error::deserialize::<_, RecipeSchema>(de) {
+            Ok(recipe) => recipe,
+            Err(error) => {
+                let pointer: String = error
+                    .path()
+                    .iter()
+                    .map(|segment| match segment {
+                        serde_path_to_error::Segment::Seq { index } => format!("/{index}"),
+                        segment => format!(
+                            "/{}",
+                            segment.to_string().replace('~', "~0").replace('/', "~1")
+                        ),
+                    })
+                    .collect();
+                let snippet = serde_json::from_str::<serde_json::Value>(json)
+                    .ok()
+                    .and_then(|value| value.pointer(&pointer).map(|v| v.to_string()))
+                    .unwrap_or_else(|| json.to_string());
+
+                let path = match error.path().to_string() {
+                    path if path == "." => "$".to_string(),
+                    path => path,
+                };
+                report
+                    .failed_fields
+                    .push(FieldError::new(path, error.into_inner(), &snippet));
+                return Vec::new();
+            }
+        };
+
+        match recipe.at_graph {
+            None if recipe
+                .at_type
+                .as_ref()
+                .is_some_and(|t| *t != AtType::Recipe) =>
+            {
+                Vec::new()
+            }
+            None => vec![recipe],
+            Some(graph) => graph
+                .into_iter()
+                .filter_map(|temp| match temp {
+                    GraphObject::Recipe(mut recipe)
+                        if recipe.at_type.as_ref().is_none_or(|t| *t == AtType::Recipe) =>
+                    {
                         recipe.at_type = Some(AtType::Recipe);
                         Some(*recipe)
-                    } else {
-                        None
                     }
-                }),
-            })
-            .ok_or_else(|| Error::DomainNotImplemented)
+                    _ => None,
+                })
+                .collect(),
+        }
+    }
+}
+
+/// Gives the recipes without an `@id` one made of the URL of the page and their position.
+fn identify(url: &str, mut recipes: Vec<RecipeSchema>) -> Vec<RecipeSchema> {
+    let page = url.split('#').next().unwrap_or(url);
+    for (i, recipe) in recipes.iter_mut().enumerate() {
+        if recipe.at_id.is_none() {
+            recipe.at_id = Some(format!("{page}#recipe-{}", i + 1));
+        }
     }
+    recipes
 }
 
 #[cfg(test)]
 mod tests {
     mod support;
+    mod tests_cache;
+    mod tests_crawler;
+    mod tests_custom;
+    mod tests_duration;
+    mod tests_heuristic;
+    mod tests_http;
+    mod tests_images;
+    mod tests_ingredients;
+    mod tests_instructions;
+    mod tests_meta;
+    mod tests_microdata;
+    mod tests_nutrition;
+    mod tests_parse_html;
+    mod tests_politeness;
+    mod tests_recipe;
+    mod tests_registry;
+    mod tests_report;
+    mod tests_scrape_all;
+    mod tests_scrape_many;
     mod tests_scraper_0_to_9;
     mod tests_scraper_a;
     mod tests_scraper_b;
@@ -134,4 +573,7 @@ mod tests {
     mod tests_scraper_w;
     mod tests_scraper_y;
     mod tests_scraper_z;
+    mod tests_servings;
+    mod tests_text;
+    mod tests_video;
 }
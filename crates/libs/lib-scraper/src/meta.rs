@@ -0,0 +1,108 @@
+//! Enrichment of recipes with the meta tags of their page.
+//!
+//! Recipes often leave out the image, description, URL or language that the page itself
+//! announces to social networks and search engines. Only the fields missing from the recipe
+//! are filled, and the source of each one is recorded in the [`ScrapeReport`].
+
+use scraper::{Html, Selector};
+use url::Url;
+
+use crate::{
+    schema::{
+        common::{ImageObjectOrUrl, LanguageOrText, TextOrTextObject},
+        recipe::RecipeSchema,
+    },
+    Result, ScrapeReport,
+};
+
+/// Where a field missing from the structured data was taken from.
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub enum FieldSource {
+    /// A `<meta>` tag, identified by its property or name, e.g. `og:image`.
+    Meta(String),
+
+    /// The `<link rel="canonical">` of the page.
+    CanonicalLink,
+
+    /// The `lang` attribute of the `<html>` element.
+    HtmlLang,
+}
+
+/// Fills the image, description, URL and language missing from the recipe.
+pub(crate) fn enrich(
+    doc: &Html,
+    url: &str,
+    recipe: &mut RecipeSchema,
+    report: &mut ScrapeReport,
+) -> Result<()> {
+    let base = Url::parse(url).ok();
+
+    if recipe.image.is_none() {
+        for property in ["og:image", "twitter:image"] {
+            if let Some(image) = content(doc, property)?.and_then(|src| resolve(&base, &src)) {
+                recipe.image = Some(ImageObjectOrUrl::Url(image));
+                report.record("image", FieldSource::Meta(property.to_string()));
+                break;
+            }
+        }
+    }
+
+    if recipe.description.is_none() {
+        if let Some(description) = content(doc, "og:description")? {
+            recipe.description = Some(TextOrTextObject::Text(description));
+            report.record(
+                "description",
+                FieldSource::Meta("og:description".to_string()),
+            );
+        }
+    }
+
+    if recipe.url.is_none() {
+        let canonical = doc
+            .select(&Selector::parse(r#"link[rel~="canonical"]"#)?)
+            .find_map(|link| resolve(&base, link.value().attr("href")?));
+
+        if let Some(canonical) = canonical {
+            recipe.url = Some(canonical);
+            report.record("url", FieldSource::CanonicalLink);
+        }
+    }
+
+    if recipe.in_language.is_none() {
+        let lang = doc
+            .root_element()
+            .value()
+            .attr("lang")
+            .map(str::trim)
+            .filter(|lang| !lang.is_empty());
+
+        if let Some(lang) = lang {
+            recipe.in_language = Some(LanguageOrText::Text(lang.to_string()));
+            report.record("inLanguage", FieldSource::HtmlLang);
+        }
+    }
+
+    Ok(())
+}
+
+/// The content of the `<meta>` tag with the property or name, e.g. `og:title`.
+pub(crate) fn content(doc: &Html, property: &str) -> Result<Option<String>> {
+    Ok(doc
+        .select(&Selector::parse("meta[property], meta[name]")?)
+        .find(|meta| {
+            let value = meta.value();
+            value.attr("property").or_else(|| value.attr("name")) == Some(property)
+        })
+        .and_then(|meta| meta.value().attr("content"))
+        .map(str::trim)
+        .filter(|content| !content.is_empty())
+        .map(str::to_string))
+}
+
+/// Resolves the link against the URL of the page, when known.
+pub(crate) fn resolve(base: &Option<Url>, link: &str) -> Option<Url> {
+    match base {
+        Some(base) => base.join(link.trim()).ok(),
+        None => Url::parse(link.trim()).ok(),
+    }
+}
@@ -0,0 +1,392 @@
+use scraper::{ElementRef, Html, Selector};
+use serde_json::{Map, Value};
+use tracing::warn;
+use url::Url;
+
+use crate::{schema::recipe::RecipeSchema, Result};
+
+/// Recipe properties that are carried over from the microdata or RDFa tree to the [`RecipeSchema`].
+///
+/// Any other property is dropped because the schema denies unknown fields.
+const RECIPE_PROPS: [&str; 18] = [
+    "author",
+    "cookTime",
+    "cookingMethod",
+    "datePublished",
+    "description",
+    "image",
+    "keywords",
+    "name",
+    "nutrition",
+    "prepTime",
+    "recipeCategory",
+    "recipeCuisine",
+    "recipeIngredient",
+    "recipeInstructions",
+    "recipeYield",
+    "suitableForDiet",
+    "totalTime",
+    "url",
+];
+
+const NUTRITION_PROPS: [&str; 12] = [
+    "calories",
+    "carbohydrateContent",
+    "cholesterolContent",
+    "fatContent",
+    "fiberContent",
+    "proteinContent",
+    "saturatedFatContent",
+    "servingSize",
+    "sodiumContent",
+    "sugarContent",
+    "transFatContent",
+    "unsaturatedFatContent",
+];
+
+/// The flavour of inline structured data found in the document.
+#[derive(Clone, Copy, Debug, PartialEq)]
+enum Syntax {
+    Microdata,
+    Rdfa,
+}
+
+impl Syntax {
+    fn scope_attr(&self) -> &'static str {
+        match self {
+            Syntax::Microdata => "itemscope",
+            Syntax::Rdfa => "typeof",
+        }
+    }
+
+    fn type_attr(&self) -> &'static str {
+        match self {
+            Syntax::Microdata => "itemtype",
+            Syntax::Rdfa => "typeof",
+        }
+    }
+
+    fn prop_attr(&self) -> &'static str {
+        match self {
+            Syntax::Microdata => "itemprop",
+            Syntax::Rdfa => "property",
+        }
+    }
+}
+
+/// A property value of an item, either a literal or a nested item.
+#[derive(Debug)]
+enum Prop {
+    Text(String),
+    Item(Item),
+}
+
+#[derive(Debug, Default)]
+struct Item {
+    types: Vec<String>,
+    props: Vec<(String, Prop)>,
+}
+
+impl Item {
+    fn is(&self, name: &str) -> bool {
+        self.types.iter().any(|t| t == name)
+    }
+
+    fn values<'a>(&'a self, name: &'a str) -> impl Iterator<Item = &'a Prop> {
+        self.props
+            .iter()
+            .filter(move |(key, _)| key == name)
+            .map(|(_, prop)| prop)
+    }
+
+    fn text(&self, name: &str) -> Option<String> {
+        self.values(name).find_map(|prop| match prop {
+            Prop::Text(text) if !text.is_empty() => Some(text.clone()),
+            _ => None,
+        })
+    }
+}
+
+/// Extracts the recipes marked up with schema.org microdata or RDFa attributes, in page order.
+///
+/// The item tree is converted to the JSON-LD shape so that it goes through the same
+/// deserializers as the `ld+json` scripts. Properties whose value cannot be deserialized
+/// are dropped rather than failing the whole recipe.
+pub(crate) fn extract(doc: &Html, url: &str) -> Result<Vec<RecipeSchema>> {
+    let base = Url::parse(url).ok();
+
+    for syntax in [Syntax::Microdata, Syntax::Rdfa] {
+        let sel = Selector::parse(&format!("[{}]", syntax.type_attr()))?;
+        let recipes: Vec<RecipeSchema> = doc
+            .select(&sel)
+            .filter(|el| {
+                el.value()
+                    .attr(syntax.type_attr())
+                    .is_some_and(|types| parse_names(types).iter().any(|t| t == "Recipe"))
+            })
+            .filter_map(|root| to_recipe(&read_item(root, syntax, base.as_ref())))
+            .collect();
+
+        if !recipes.is_empty() {
+            return Ok(recipes);
+        }
+    }
+
+    Ok(Vec::new())
+}
+
+/// Whether the document has any microdata or RDFa item, recipe or not.
+pub(crate) fn has_items(doc: &Html) -> bool {
+    Selector::parse("[itemscope], [typeof]").is_ok_and(|sel| doc.select(&sel).next().is_some())
+}
+
+fn read_item(el: ElementRef, syntax: Syntax, base: Option<&Url>) -> Item {
+    let mut item = Item {
+        types: el
+            .value()
+            .attr(syntax.type_attr())
+            .map(parse_names)
+            .unwrap_or_default(),
+        props: Vec::new(),
+    };
+    read_props(el, syntax, base, &mut item);
+    item
+}
+
+fn read_props(el: ElementRef, syntax: Syntax, base: Option<&Url>, item: &mut Item) {
+    for child in el.children().filter_map(ElementRef::wrap) {
+        let names = child.value().attr(syntax.prop_attr()).map(parse_names);
+        let is_scope = child.value().attr(syntax.scope_attr()).is_some();
+
+        match names {
+            Some(names) if is_scope => {
+                for name in names {
+                    item.props
+                        .push((name, Prop::Item(read_item(child, syntax, base))));
+                }
+            }
+            Some(names) => {
+                let value = read_value(child, base);
+                for name in names {
+                    item.props.push((name, Prop::Text(value.clone())));
+                }
+                read_props(child, syntax, base, item);
+            }
+            None if is_scope => {}
+            None => read_props(child, syntax, base, item),
+        }
+    }
+}
+
+fn read_value(el: ElementRef, base: Option<&Url>) -> String {
+    let node = el.value();
+    if let Some(content) = node.attr("content") {
+        return content.trim().to_string();
+    }
+
+    let url_attr = match node.name() {
+        "audio" | "embed" | "iframe" | "img" | "source" | "track" | "video" => Some("src"),
+        "a" | "area" | "link" => Some("href"),
+        "object" => Some("data"),
+        _ => None,
+    };
+    if let Some(link) = url_attr.and_then(|attr| node.attr(attr)) {
+        return match base.and_then(|base| base.join(link).ok()) {
+            Some(url) => url.to_string(),
+            None => link.to_string(),
+        };
+    }
+
+    if let Some(value) = node
+        .attr("datetime")
+        .or_else(|| node.attr("value"))
+        .or_else(|| node.attr("resource"))
+    {
+        return value.trim().to_string();
+    }
+
+    el.text()
+        .collect::<Vec<_>>()
+        .join(" ")
+        .split_whitespace()
+        .collect::<Vec<_>>()
+        .join(" ")
+}
+
+/// Reduces names such as `https://schema.org/Recipe` or `schema:Recipe` to `Recipe`.
+fn parse_names(attr: &str) -> Vec<String> {
+    attr.split_whitespace().map(short_name).collect()
+}
+
+fn short_name(name: &str) -> String {
+    name.trim_end_matches('/')
+        .rsplit(['/', ':'])
+        .next()
+        .unwrap_or(name)
+        .to_string()
+}
+
+/// The recipe of the item. Returns `None` when its properties do not make a recipe together.
+fn to_recipe(item: &Item) -> Option<RecipeSchema> {
+    let mut map = Map::new();
+    map.insert("@type".to_string(), Value::String("Recipe".to_string()));
+
+    for key in RECIPE_PROPS {
+        if let Some(value) = to_value(item, key) {
+            map.insert(key.to_string(), value);
+        }
+    }
+
+    // The deserializers are strict, so only keep the properties that parse on their own.
+    map.retain(|key, value| {
+        key == "@type" || {
+            let single = Map::from_iter([(key.clone(), value.clone())]);
+            serde_json::from_value::<RecipeSchema>(Value::Object(single)).is_ok()
+        }
+    });
+
+    match serde_json::from_value(Value::Object(map)) {
+        Ok(recipe) => Some(recipe),
+        Err(ex) => {
+            warn!("Skipping the microdata recipe, which could not be deserialized: {ex}");
+            None
+        }
+    }
+}
+
+fn to_value(item: &Item, key: &str) -> Option<Value> {
+    let texts = || -> Vec<Value> {
+        item.values(key)
+            .filter_map(|prop| match prop {
+                Prop::Text(text) if !text.is_empty() => Some(Value::String(text.clone())),
+                Prop::Item(nested) => nested
+                    .text("name")
+                    .or_else(|| nested.text("text"))
+                    .map(Value::String),
+                _ => None,
+            })
+            .collect()
+    };
+
+    match key {
+        "recipeIngredient" => {
+            let mut values = texts();
+            if values.is_empty() {
+                // The legacy data-vocabulary and early schema.org markup used `ingredients`.
+                values = item
+                    .values("ingredients")
+                    .filter_map(|prop| match prop {
+                        Prop::Text(text) if !text.is_empty() => Some(Value::String(text.clone())),
+                        _ => None,
+                    })
+                    .collect();
+            }
+            (!values.is_empty()).then_some(Value::Array(values))
+        }
+        "recipeInstructions" => {
+            let steps: Vec<Value> = item
+                .values(key)
+                .flat_map(|prop| match prop {
+                    Prop::Text(text) if !text.is_empty() => vec![step_value(text.clone())],
+                    Prop::Item(nested) if nested.is("HowToSection") => {
+                        let steps: Vec<Value> = nested
+                            .values("itemListElement")
+                            .filter_map(|prop| match prop {
+                                Prop::Text(text) if !text.is_empty() => Some(text.clone()),
+                                Prop::Item(step) => step.text("text").or_else(|| step.text("name")),
+                                _ => None,
+                            })
+                            .map(step_value)
+                            .collect();
+
+                        let mut section = Map::new();
+                        section.insert(
+                            "@type".to_string(),
+                            Value::String("HowToSection".to_string()),
+                        );
+                        if let Some(name) = nested.text("name") {
+                            section.insert("name".to_string(), Value::String(name));
+                        }
+                        section.insert("itemListElement".to_string(), Value::Array(steps));
+                        vec![Value::Object(section)]
+                    }
+                    Prop::Item(nested) => nested
+                        .text("text")
+                        .or_else(|| nested.text("name"))
+                        .map(step_value)
+                        .into_iter()
+                        .collect(),
+                    _ => Vec::new(),
+                })
+                .collect();
+            (!steps.is_empty()).then_some(Value::Array(steps))
+        }
+        "recipeCategory" | "recipeCuisine" => {
+            let values = texts();
+            match values.len() {
+                0 => None,
+                1 => values.into_iter().next(),
+                _ => Some(Value::Array(values)),
+            }
+        }
+        "author" => item.values(key).find_map(|prop| {
+            let (at_type, name, url) = match prop {
+                Prop::Text(text) if !text.is_empty() => ("Person", text.clone(), None),
+                Prop::Item(nested) => (
+                    if nested.is("Organization") {
+                        "Organization"
+                    } else {
+                        "Person"
+                    },
+                    nested.text("name")?,
+                    nested.text("url"),
+                ),
+                _ => return None,
+            };
+
+            let mut author = Map::new();
+            author.insert("@type".to_string(), Value::String(at_type.to_string()));
+            author.insert("name".to_string(), Value::String(name));
+            if let Some(url) = url.filter(|url| Url::parse(url).is_ok()) {
+                author.insert("url".to_string(), Value::String(url));
+            }
+            Some(Value::Object(author))
+        }),
+        "nutrition" => item.values(key).find_map(|prop| match prop {
+            Prop::Item(nested) => {
+                let mut nutrition = Map::new();
+                nutrition.insert(
+                    "@type".to_string(),
+                    Value::String("NutritionInformation".to_string()),
+                );
+                for name in NUTRITION_PROPS {
+                    if let Some(text) = nested.text(name) {
+                        nutrition.insert(name.to_string(), Value::String(text));
+                    }
+                }
+                Some(Value::Object(nutrition))
+            }
+            _ => None,
+        }),
+        "image" => item.values(key).find_map(|prop| match prop {
+            Prop::Text(text) if !text.is_empty() => Some(Value::String(text.clone())),
+            Prop::Item(nested) => nested
+                .text("url")
+                .or_else(|| nested.text("contentUrl"))
+                .map(Value::String),
+            _ => None,
+        }),
+        "suitableForDiet" => texts()
+            .into_iter()
+            .next()
+            .and_then(|value| value.as_str().map(|diet| Value::String(short_name(diet)))),
+        _ => texts().into_iter().next(),
+    }
+}
+
+fn step_value(text: String) -> Value {
+    let mut step = Map::new();
+    step.insert("@type".to_string(), Value::String("HowToStep".to_string()));
+    step.insert("text".to_string(), Value::String(text));
+    Value::Object(step)
+}
@@ -0,0 +1,229 @@
+//! Keeps the scraper from hammering the websites: a token bucket per website paces the
+//! requests, and the paths disallowed by the `robots.txt` of the website are refused.
+
+use std::{
+    collections::HashMap,
+    sync::{Arc, Mutex},
+    time::{Duration, Instant},
+};
+use tokio::sync::OnceCell;
+use tracing::warn;
+use url::Url;
+
+use crate::{config::ScraperConfig, robots::Robots, websites::Website, Error, HttpClient, Result};
+
+/// The per-website state of the scraper.
+pub(crate) struct Politeness {
+    rate: Option<Rate>,
+    user_agent: String,
+    respect_robots_txt: bool,
+    buckets: Mutex<HashMap<Website, TokenBucket>>,
+    robots: Mutex<HashMap<Website, Arc<OnceCell<Robots>>>>,
+}
+
+#[derive(Clone, Copy, Debug)]
+pub(crate) struct Rate {
+    pub(crate) per_second: f64,
+    pub(crate) burst: u32,
+}
+
+struct TokenBucket {
+    tokens: f64,
+    per_second: f64,
+    burst: f64,
+    updated_at: Instant,
+}
+
+impl TokenBucket {
+    fn new(rate: Rate) -> Self {
+        Self {
+            tokens: rate.burst as f64,
+            per_second: rate.per_second,
+            burst: rate.burst as f64,
+            updated_at: Instant::now(),
+        }
+    }
+
+    /// Takes a token, or returns how long to wait until one is available.
+    fn take(&mut self) -> Option<Duration> {
+        let now = Instant::now();
+        let elapsed = now.duration_since(self.updated_at).as_secs_f64();
+        self.tokens = (self.tokens + elapsed * self.per_second).min(self.burst);
+        self.updated_at = now;
+
+        if self.tokens >= 1.0 {
+            self.tokens -= 1.0;
+            None
+        } else {
+            Some(Duration::from_secs_f64(
+                (1.0 - self.tokens) / self.per_second,
+            ))
+        }
+    }
+}
+
+impl Politeness {
+    pub(crate) fn new(config: &ScraperConfig) -> Self {
+        Self {
+            rate: config.rate_limit,
+            user_agent: config.user_agent.clone(),
+            respect_robots_txt: config.respect_robots_txt,
+            buckets: Mutex::new(HashMap::new()),
+            robots: Mutex::new(HashMap::new()),
+        }
+    }
+
+    /// Neither paces the requests nor reads the `robots.txt` files.
+    #[cfg(test)]
+    pub(crate) fn disabled() -> Self {
+        Self {
+            rate: None,
+            user_agent: String::new(),
+            respect_robots_txt: false,
+            buckets: Mutex::new(HashMap::new()),
+            robots: Mutex::new(HashMap::new()),
+        }
+    }
+
+    /// Waits for the turn of the request and checks that `robots.txt` allows it.
+    pub(crate) async fn wait_async(
+        &self,
+        client: &(dyn HttpClient + Sync + Send),
+        host: &Website,
+        url: &str,
+    ) -> Result<()> {
+        if self.respect_robots_txt {
+            let cell = self.robots_cell(host);
+            let robots = cell
+                .get_or_try_init(|| async {
+                    match robots_url(url) {
+                        Some(robots_url) => self
+                            .parse_robots(client.get_async(host.clone(), &robots_url).await)
+                            .ok_or_else(|| disallowed(url)),
+                        None => Ok(Robots::default()),
+                    }
+                })
+                .await?;
+            self.check(host, url, robots)?;
+        }
+
+        while let Some(delay) = self.take(host) {
+            tokio::time::sleep(delay).await;
+        }
+        Ok(())
+    }
+
+    /// Like [`Politeness::wait_async`], but blocks the current thread.
+    pub(crate) fn wait(
+        &self,
+        client: &(dyn HttpClient + Sync + Send),
+        host: &Website,
+        url: &str,
+    ) -> Result<()> {
+        if self.respect_robots_txt {
+            let cell = self.robots_cell(host);
+            let robots = match cell.get() {
+                Some(robots) => robots,
+                None => {
+                    let robots = match robots_url(url) {
+                        Some(robots_url) => self
+                            .parse_robots(client.get(host.clone(), &robots_url))
+                            .ok_or_else(|| disallowed(url))?,
+                        None => Robots::default(),
+                    };
+                    // Another thread may have been faster, in which case its rules are used.
+                    let _ = cell.set(robots);
+                    cell.get().expect("robots.txt rules were just set")
+                }
+            };
+            self.check(host, url, robots)?;
+        }
+
+        while let Some(delay) = self.take(host) {
+            std::thread::sleep(delay);
+        }
+        Ok(())
+    }
+
+    fn robots_cell(&self, host: &Website) -> Arc<OnceCell<Robots>> {
+        self.robots
+            .lock()
+            .unwrap_or_else(|poisoned| poisoned.into_inner())
+            .entry(host.clone())
+            .or_default()
+            .clone()
+    }
+
+    /// A missing `robots.txt`, which the server answers with a client error, allows everything.
+    /// An unreachable one, because of a server or network error, disallows everything and
+    /// returns `None` so that it is not cached and the next request tries again (RFC 9309
+    /// §2.3.1.3 and §2.3.1.4).
+    fn parse_robots(&self, content: Result<String>) -> Option<Robots> {
+        match content {
+            Ok(content) => Some(Robots::parse(&content, &self.user_agent)),
+            Err(Error::HttpStatus { status, .. }) if (400..500).contains(&status) => {
+                Some(Robots::default())
+            }
+            // The offline cache never contacts the server, so there is nothing to wait for.
+            Err(Error::CacheMiss { .. }) => Some(Robots::default()),
+            Err(ex) => {
+                warn!("Disallowing the request, as the robots.txt could not be fetched: {ex}");
+                None
+            }
+        }
+    }
+
+    fn check(&self, host: &Website, url: &str, robots: &Robots) -> Result<()> {
+        let path = Url::parse(url)
+            .map(|url| match url.query() {
+                Some(query) => format!("{}?{query}", url.path()),
+                None => url.path().to_string(),
+            })
+            .unwrap_or_else(|_| "/".to_string());
+
+        if !robots.is_allowed(&path) {
+            return Err(disallowed(url));
+        }
+
+        if let (Some(delay), Some(rate)) = (robots.crawl_delay(), self.rate) {
+            let per_second = 1.0 / delay.as_secs_f64().max(f64::EPSILON);
+            let mut buckets = self
+                .buckets
+                .lock()
+                .unwrap_or_else(|poisoned| poisoned.into_inner());
+            let bucket = buckets
+                .entry(host.clone())
+                .or_insert_with(|| TokenBucket::new(rate));
+            if per_second < bucket.per_second {
+                bucket.per_second = per_second;
+                bucket.burst = 1.0;
+                bucket.tokens = bucket.tokens.min(1.0);
+            }
+        }
+        Ok(())
+    }
+
+    fn take(&self, host: &Website) -> Option<Duration> {
+        let rate = self.rate?;
+        self.buckets
+            .lock()
+            .unwrap_or_else(|poisoned| poisoned.into_inner())
+            .entry(host.clone())
+            .or_insert_with(|| TokenBucket::new(rate))
+            .take()
+    }
+}
+
+fn disallowed(url: &str) -> Error {
+    Error::DisallowedByRobots {
+        url: url.to_string(),
+    }
+}
+
+fn robots_url(url: &str) -> Option<String> {
+    Url::parse(url)
+        .ok()?
+        .join("/robots.txt")
+        .ok()
+        .map(|url| url.to_string())
+}
@@ -0,0 +1,198 @@
+//! The websites added at runtime.
+//!
+//! A registry file lists websites in JSON or TOML. An entry whose host is one of the
+//! supported websites adds aliases or extraction hints to it, and any other entry adds a
+//! [`Website::Custom`].
+//!
+//! ```toml
+//! [[websites]]
+//! host = "www.allrecipes.com"
+//! aliases = ["allrecipes.com", "m.allrecipes.com"]
+//!
+//! [[websites]]
+//! host = "family-recipes.example"
+//! aliases = ["m.family-recipes.example"]
+//! url = "https://family-recipes.example/recipes"
+//!
+//! [websites.selectors]
+//! name = "h1.title"
+//! ingredients = ".ingredients li"
+//! ```
+
+use scraper::Selector;
+use serde::Deserialize;
+use std::{
+    collections::{BTreeMap, HashMap},
+    path::Path,
+    sync::Arc,
+};
+use url::Url;
+
+use crate::{
+    custom::{SelectorExtractor, SiteExtractor},
+    websites::{websites_map_to_domain, websites_map_to_website, Website},
+    Error, Result,
+};
+
+/// A website of a registry file.
+#[derive(Clone, Debug, Default, Deserialize, PartialEq)]
+#[serde(deny_unknown_fields)]
+pub struct WebsiteEntry {
+    /// The domain of the website, e.g. `www.allrecipes.com`.
+    pub host: String,
+
+    /// Other domains serving the same pages, e.g. `m.allrecipes.com`.
+    #[serde(default)]
+    pub aliases: Vec<String>,
+
+    /// The home page of the website. Defaults to `https://{host}`.
+    pub url: Option<String>,
+
+    /// The selectors of the fields the structured data of the website gets wrong.
+    pub selectors: Option<SelectorExtractor>,
+}
+
+#[derive(Deserialize)]
+#[serde(deny_unknown_fields)]
+struct RegistryFile {
+    #[serde(default)]
+    websites: Vec<WebsiteEntry>,
+}
+
+/// The websites added to the built-in ones.
+///
+/// ```ignore
+/// let scraper = ScraperConfig::default()
+///     .registry(Registry::load("websites.toml")?)
+///     .build()?;
+/// ```
+#[derive(Clone, Debug, Default)]
+pub struct Registry {
+    hosts: HashMap<String, Website>,
+    urls: HashMap<Website, String>,
+    extractors: HashMap<Website, SelectorExtractor>,
+}
+
+impl Registry {
+    /// Creates the registry from its entries.
+    ///
+    /// Fails when an entry has no host or an invalid selector.
+    pub fn new(entries: Vec<WebsiteEntry>) -> Result<Self> {
+        let mut registry = Self::default();
+
+        for entry in entries {
+            let host = entry.host.trim().to_lowercase();
+            if host.is_empty() {
+                return Err(Error::NoHost);
+            }
+
+            let website = match websites_map_to_website().get(&host) {
+                Some(website) => website.clone(),
+                None => Website::Custom(host.clone()),
+            };
+
+            if let Some(selectors) = entry.selectors {
+                for selector in [
+                    &selectors.name,
+                    &selectors.description,
+                    &selectors.image,
+                    &selectors.ingredients,
+                    &selectors.instructions,
+                ]
+                .into_iter()
+                .flatten()
+                {
+                    Selector::parse(selector)?;
+                }
+                registry.extractors.insert(website.clone(), selectors);
+            }
+
+            if let Some(url) = entry.url {
+                registry.urls.insert(website.clone(), url);
+            } else if matches!(website, Website::Custom(_)) {
+                registry
+                    .urls
+                    .insert(website.clone(), format!("https://{host}"));
+            }
+
+            for alias in entry.aliases {
+                let alias = alias.trim().to_lowercase();
+                if !alias.is_empty() {
+                    registry.hosts.insert(alias, website.clone());
+                }
+            }
+            registry.hosts.insert(host, website);
+        }
+
+        Ok(registry)
+    }
+
+    /// Reads a registry in JSON.
+    pub fn from_json(content: &str) -> Result<Self> {
+        let file: RegistryFile = serde_json::from_str(content)?;
+        Self::new(file.websites)
+    }
+
+    /// Reads a registry in TOML.
+    pub fn from_toml(content: &str) -> Result<Self> {
+        let file: RegistryFile = toml::from_str(content)?;
+        Self::new(file.websites)
+    }
+
+    /// Reads a registry file. Files ending in `.toml` are read as TOML and the others as JSON.
+    pub fn load(path: impl AsRef<Path>) -> Result<Self> {
+        let path = path.as_ref();
+        let content = std::fs::read_to_string(path)?;
+
+        match path.extension().and_then(|ext| ext.to_str()) {
+            Some("toml") => Self::from_toml(&content),
+            _ => Self::from_json(&content),
+        }
+    }
+
+    /// The website serving the URL, if its domain is in the registry.
+    pub fn website(&self, url: &str) -> Option<Website> {
+        let url = Url::parse(url).ok()?;
+        self.hosts.get(url.domain()?).cloned()
+    }
+
+    /// The host and home page of every website, built-in ones included, sorted by host.
+    ///
+    /// The hosts are stripped of their `www.` to match the `websites` table.
+    pub fn websites(&self) -> Vec<(String, String)> {
+        let mut websites: BTreeMap<String, String> = websites_map_to_domain()
+            .iter()
+            .map(|(website, domain)| {
+                let url = self
+                    .urls
+                    .get(website)
+                    .cloned()
+                    .unwrap_or_else(|| format!("https://{domain}"));
+                (strip_www(domain), url)
+            })
+            .collect();
+
+        for (website, url) in &self.urls {
+            if let Website::Custom(host) = website {
+                websites.insert(strip_www(host), url.clone());
+            }
+        }
+
+        websites.into_iter().collect()
+    }
+
+    pub(crate) fn extractors(
+        &self,
+    ) -> impl Iterator<Item = (Website, Arc<dyn SiteExtractor>)> + '_ {
+        self.extractors.iter().map(|(website, extractor)| {
+            (
+                website.clone(),
+                Arc::new(extractor.clone()) as Arc<dyn SiteExtractor>,
+            )
+        })
+    }
+}
+
+fn strip_www(host: &str) -> String {
+    host.strip_prefix("www.").unwrap_or(host).to_string()
+}
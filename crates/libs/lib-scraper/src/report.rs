@@ -0,0 +1,83 @@
+//! Diagnostics collected while a page is scraped.
+
+use std::collections::BTreeMap;
+
+use crate::meta::FieldSource;
+
+/// How strictly the structured data of a page is deserialized.
+#[derive(Clone, Copy, Debug, Default, Eq, PartialEq)]
+pub enum ParseMode {
+    /// Unknown properties and properties that fail to deserialize are dropped one by one and
+    /// recorded in the [`ScrapeReport`]. The rest of the recipe is kept.
+    #[default]
+    Lenient,
+
+    /// A single unknown or malformed property discards the whole `ld+json` block.
+    Strict,
+}
+
+/// What the scraper had to ignore to build the recipe.
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct ScrapeReport {
+    /// The path of every property that is not part of the schema, e.g. `nutrition.fooContent`.
+    pub unknown_keys: Vec<String>,
+
+    /// The properties whose value could not be deserialized.
+    pub failed_fields: Vec<FieldError>,
+
+    /// Whether the website is not one of the supported websites, so the recipe comes from
+    /// its structured data without any site-specific fix.
+    pub unverified_site: bool,
+
+    /// How likely a recipe guessed from the layout of a page without structured data was
+    /// read correctly, from 0 to 1. It is `None` when the recipe comes from structured data.
+    ///
+    /// A low confidence is a hint to have the recipe reviewed before it is saved.
+    pub confidence: Option<f32>,
+
+    /// The fields missing from the structured data that were filled from the tags of the
+    /// page, e.g. `image` from `og:image`.
+    pub filled_fields: BTreeMap<String, FieldSource>,
+}
+
+impl ScrapeReport {
+    /// Whether the structured data was used as is.
+    pub fn is_clean(&self) -> bool {
+        self.unknown_keys.is_empty() && self.failed_fields.is_empty()
+    }
+
+    pub(crate) fn record(&mut self, field: &str, source: FieldSource) {
+        self.filled_fields.insert(field.to_string(), source);
+    }
+}
+
+/// A property that could not be deserialized.
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct FieldError {
+    /// The path of the property, e.g. `recipeYield`, or `$` for the whole block.
+    pub path: String,
+
+    /// Why the value was rejected.
+    pub message: String,
+
+    /// The raw JSON of the value, truncated to [`MAX_SNIPPET_LEN`] bytes.
+    pub snippet: String,
+}
+
+/// The maximum length of a [`FieldError::snippet`].
+pub const MAX_SNIPPET_LEN: usize = 256;
+
+impl FieldError {
+    pub(crate) fn new(path: impl Into<String>, message: impl ToString, json: &str) -> Self {
+        let mut end = json.len().min(MAX_SNIPPET_LEN);
+        while !json.is_char_boundary(end) {
+            end -= 1;
+        }
+
+        Self {
+            path: path.into(),
+            message: message.to_string(),
+            snippet: json[..end].to_string(),
+        }
+    }
+}
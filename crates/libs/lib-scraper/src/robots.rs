@@ -0,0 +1,150 @@
+//! A parser for the [robots exclusion protocol](https://www.rfc-editor.org/rfc/rfc9309).
+
+use std::time::Duration;
+
+/// The rules of a `robots.txt` file that apply to one user agent.
+#[derive(Clone, Debug, Default, PartialEq)]
+pub(crate) struct Robots {
+    rules: Vec<Rule>,
+    crawl_delay: Option<Duration>,
+}
+
+#[derive(Clone, Debug, PartialEq)]
+struct Rule {
+    allow: bool,
+    pattern: String,
+}
+
+impl Robots {
+    /// Parses the file and keeps the group of the user agent, or the `*` group when no group
+    /// names it.
+    ///
+    /// Only the product token of the user agent is matched, e.g. `mozilla` for
+    /// `Mozilla/5.0 (X11; Linux x86_64)`.
+    pub(crate) fn parse(content: &str, user_agent: &str) -> Self {
+        let token = user_agent
+            .split(['/', ' '])
+            .next()
+            .unwrap_or_default()
+            .to_lowercase();
+
+        let mut specific = Robots::default();
+        let mut wildcard = Robots::default();
+        let mut found_specific = false;
+
+        // The agents of the group being read, and whether its rules started.
+        let mut agents: Vec<String> = Vec::new();
+        let mut in_rules = false;
+
+        for line in content.lines() {
+            let line = line.split('#').next().unwrap_or_default().trim();
+            let Some((key, value)) = line.split_once(':') else {
+                continue;
+            };
+            let key = key.trim().to_lowercase();
+            let value = value.trim();
+
+            if key == "user-agent" {
+                if in_rules {
+                    agents.clear();
+                    in_rules = false;
+                }
+                agents.push(value.to_lowercase());
+                continue;
+            }
+
+            in_rules = true;
+            let is_specific = !token.is_empty() && agents.contains(&token);
+            let is_wildcard = agents.iter().any(|a| a == "*");
+            found_specific |= is_specific;
+
+            let targets: Vec<&mut Robots> = match (is_specific, is_wildcard) {
+                (true, true) => vec![&mut specific, &mut wildcard],
+                (true, false) => vec![&mut specific],
+                (false, true) => vec![&mut wildcard],
+                (false, false) => continue,
+            };
+
+            for robots in targets {
+                match key.as_str() {
+                    "allow" | "disallow" if !value.is_empty() => robots.rules.push(Rule {
+                        allow: key == "allow",
+                        pattern: value.to_string(),
+                    }),
+                    "crawl-delay" => {
+                        robots.crawl_delay = value
+                            .parse::<f64>()
+                            .ok()
+                            .filter(|secs| secs.is_finite() && *secs >= 0.0)
+                            .map(Duration::from_secs_f64)
+                    }
+                    _ => {}
+                }
+            }
+        }
+
+        if found_specific {
+            specific
+        } else {
+            wildcard
+        }
+    }
+
+    /// Whether the path, with its query, may be fetched.
+    ///
+    /// The most specific matching rule wins. An `Allow` rule wins a tie.
+    pub(crate) fn is_allowed(&self, path: &str) -> bool {
+        self.rules
+            .iter()
+            .filter(|rule| matches(&rule.pattern, path))
+            .max_by_key(|rule| (rule.pattern.len(), rule.allow))
+            .is_none_or(|rule| rule.allow)
+    }
+
+    /// How long to wait between two requests, if the website asks for it.
+    pub(crate) fn crawl_delay(&self) -> Option<Duration> {
+        self.crawl_delay
+    }
+}
+
+/// The URLs of the sitemaps listed in the file. They apply to every user agent.
+pub(crate) fn sitemaps(content: &str) -> Vec<String> {
+    content
+        .lines()
+        .filter_map(|line| {
+            let (key, value) = line.split_once(':')?;
+            let value = value.split('#').next().unwrap_or_default().trim();
+            (key.trim().eq_ignore_ascii_case("sitemap") && !value.is_empty())
+                .then(|| value.to_string())
+        })
+        .collect()
+}
+
+/// Matches the path against a pattern where `*` stands for any sequence of characters and a
+/// trailing `$` anchors the end of the path.
+fn matches(pattern: &str, path: &str) -> bool {
+    let (pattern, anchored) = match pattern.strip_suffix('$') {
+        Some(pattern) => (pattern, true),
+        None => (pattern, false),
+    };
+
+    let mut parts = pattern.split('*');
+    let first = parts.next().unwrap_or_default();
+    let Some(mut rest) = path.strip_prefix(first) else {
+        return false;
+    };
+
+    let parts: Vec<&str> = parts.collect();
+    for (i, part) in parts.iter().enumerate() {
+        let is_last = i == parts.len() - 1;
+        if is_last && anchored {
+            return rest.ends_with(part);
+        }
+        match rest.find(part) {
+            Some(pos) => rest = &rest[pos + part.len()..],
+            None => return false,
+        }
+    }
+
+    !anchored || rest.is_empty()
+}
@@ -3,13 +3,15 @@ use std::{collections::HashMap, fmt::Formatter, str::FromStr, vec::Vec};
 use serde::{
     de,
     de::{Error, MapAccess, SeqAccess},
-    Deserialize, Deserializer,
+    Deserialize, Deserializer, Serialize, Serializer,
 };
+use serde_with::skip_serializing_none;
 use url::Url;
 
 use crate::schema::AtType;
 
-#[derive(Debug, PartialEq)]
+#[derive(Debug, PartialEq, Serialize)]
+#[serde(untagged)]
 pub enum Action {
     Item(ActionType),
     Items(Vec<ActionType>),
@@ -19,7 +21,8 @@ pub enum Action {
 /// happens at a location with the help of an inanimate instrument. The execution of the action
 /// may produce a result. Specific action sub-type documentation specifies the exact expectation
 /// of each argument/role.
-#[derive(Debug, Default, Deserialize, PartialEq)]
+#[skip_serializing_none]
+#[derive(Debug, Default, Deserialize, Serialize, PartialEq)]
 #[serde(deny_unknown_fields)]
 pub struct ActionType {
     #[serde(rename = "@type")]
@@ -69,7 +72,8 @@ impl<'de> Deserialize<'de> for Action {
 }
 
 /// The average rating based on multiple ratings or reviews.
-#[derive(Debug, Default, Deserialize, PartialEq)]
+#[skip_serializing_none]
+#[derive(Debug, Default, Deserialize, Serialize, PartialEq)]
 #[serde(deny_unknown_fields, rename_all = "camelCase")]
 pub struct AggregateRating {
     #[serde(rename = "@type", default = "set_aggregate_rating_type")]
@@ -143,22 +147,23 @@ where
     deserializer.deserialize_any(Visitor)
 }
 
-#[derive(Debug, PartialEq)]
+#[derive(Debug, PartialEq, Serialize)]
+#[serde(untagged)]
 pub enum AudioObjectOrClipOrMusicRecording {
     AudioObject(AudioObjectType),
     Clip(ClipType),
     MusicRecording(MusicRecordingType),
 }
 
-#[derive(Debug, Deserialize, PartialEq)]
+#[derive(Debug, Deserialize, Serialize, PartialEq)]
 #[serde(deny_unknown_fields)]
 pub struct AudioObjectType {}
 
-#[derive(Debug, Deserialize, PartialEq)]
+#[derive(Debug, Deserialize, Serialize, PartialEq)]
 #[serde(deny_unknown_fields)]
 pub struct ClipType {}
 
-#[derive(Debug, Deserialize, PartialEq)]
+#[derive(Debug, Deserialize, Serialize, PartialEq)]
 #[serde(deny_unknown_fields)]
 pub struct MusicRecordingType {}
 
@@ -210,11 +215,12 @@ impl<'de> Deserialize<'de> for AudioObjectOrClipOrMusicRecording {
 
 /// A comment on an item - for example, a comment on a blog post. The comment's content is expressed
 /// via the text property, and its topic via about, properties shared with all CreativeWorks.
-#[derive(Debug, Deserialize, PartialEq)]
+#[derive(Debug, Deserialize, Serialize, PartialEq)]
 #[serde(deny_unknown_fields)]
 pub struct CommentType {}
 
-#[derive(Debug, PartialEq)]
+#[derive(Debug, PartialEq, Serialize)]
+#[serde(untagged)]
 pub enum ClipOrVideoObject {
     Clip(ClipType),
     VideoObject(Box<VideoObjectType>),
@@ -261,11 +267,12 @@ impl<'de> Deserialize<'de> for ClipOrVideoObject {
 }
 
 /// A country.
-#[derive(Debug, Deserialize, PartialEq)]
+#[derive(Debug, Deserialize, Serialize, PartialEq)]
 #[serde(deny_unknown_fields)]
 pub struct CountryType {}
 
-#[derive(Debug, PartialEq)]
+#[derive(Debug, PartialEq, Serialize)]
+#[serde(untagged)]
 pub enum CreativeWorkOrHowToSectionOrHowToStepOrText {
     CreativeWork(CreativeWorkType),
     HowToSection(HowToSectionType),
@@ -333,10 +340,15 @@ impl<'de> Deserialize<'de> for CreativeWorkOrHowToSectionOrHowToStepOrText {
     }
 }
 
-#[derive(Debug, PartialEq)]
+#[derive(Debug, PartialEq, Serialize)]
+#[serde(untagged)]
 pub enum CreativeWorkOrItemListOrText {
     CreativeWork(Box<CreativeWorkType>),
     ItemList(Vec<HowTo>),
+
+    /// An ordered list with at least one HowToSection. The steps outside a section are grouped
+    /// in unnamed sections so that the order is preserved.
+    Sections(Vec<HowToSectionType>),
     Text(String),
 }
 
@@ -380,22 +392,34 @@ impl<'de> Deserialize<'de> for CreativeWorkOrItemListOrText {
             where
                 A: SeqAccess<'de>,
             {
-                let mut vec: Vec<HowTo> = Vec::new();
-                while let Some(mut object) = seq.next_element::<HowTo>()? {
-                    if let Some(name) = object.name {
-                        object.name = Some(deserialize_trim(name));
-                    }
-                    object.text = deserialize_trim(object.text);
-                    vec.push(object);
+                let mut values = Vec::new();
+                while let Some(value) = seq.next_element::<serde_json::Value>()? {
+                    values.push(value);
                 }
-                Ok(ItemList(vec))
+                read_instructions(values).map_err(A::Error::custom)
             }
 
             fn visit_map<A>(self, map: A) -> Result<Self::Value, A::Error>
             where
                 A: MapAccess<'de>,
             {
-                let c = CreativeWorkType::deserialize(de::value::MapAccessDeserializer::new(map))?;
+                let value =
+                    serde_json::Value::deserialize(de::value::MapAccessDeserializer::new(map))?;
+
+                if has_type(&value, "ItemList") {
+                    let elements = value
+                        .get("itemListElement")
+                        .cloned()
+                        .map(into_values)
+                        .unwrap_or_default();
+                    return read_instructions(elements).map_err(A::Error::custom);
+                }
+                if has_type(&value, "HowToSection") {
+                    return read_instructions(vec![value]).map_err(A::Error::custom);
+                }
+
+                let c: CreativeWorkType =
+                    serde_json::from_value(value).map_err(A::Error::custom)?;
                 Ok(CreativeWork(Box::new(c)))
             }
         }
@@ -404,6 +428,147 @@ impl<'de> Deserialize<'de> for CreativeWorkOrItemListOrText {
     }
 }
 
+/// Reads the steps and sections of instructions given as a list, e.g. the `itemListElement` of
+/// an `ItemList`.
+fn read_instructions(
+    values: Vec<serde_json::Value>,
+) -> Result<CreativeWorkOrItemListOrText, serde_json::Error> {
+    let mut steps: Vec<HowTo> = Vec::new();
+    let mut sections: Vec<HowToSectionType> = Vec::new();
+
+    for value in values {
+        if has_type(&value, "HowToSection") {
+            if !steps.is_empty() {
+                sections.push(HowToSectionType {
+                    at_type: AtType::HowToSection,
+                    item_list_element: std::mem::take(&mut steps),
+                    ..Default::default()
+                });
+            }
+            read_section(value, &mut sections)?;
+        } else {
+            steps.push(read_step(value)?);
+        }
+    }
+
+    if sections.is_empty() {
+        return Ok(CreativeWorkOrItemListOrText::ItemList(steps));
+    }
+
+    if !steps.is_empty() {
+        sections.push(HowToSectionType {
+            at_type: AtType::HowToSection,
+            item_list_element: steps,
+            ..Default::default()
+        });
+    }
+    Ok(CreativeWorkOrItemListOrText::Sections(sections))
+}
+
+/// Reads a HowToSection. The sections nested in it keep their place among its steps: the
+/// steps that follow a nested section make an unnamed section of their own.
+fn read_section(
+    mut value: serde_json::Value,
+    sections: &mut Vec<HowToSectionType>,
+) -> Result<(), serde_json::Error> {
+    let elements = value
+        .as_object_mut()
+        .and_then(|object| object.remove("itemListElement"))
+        .map(into_values)
+        .unwrap_or_default();
+
+    let mut section: HowToSectionType = serde_json::from_value(value)?;
+    section.name = section.name.map(deserialize_trim);
+
+    let mut parent = Some(section);
+    let mut steps = Vec::new();
+    for element in elements {
+        if has_type(&element, "HowToSection") {
+            close_section(&mut parent, &mut steps, sections);
+            read_section(element, sections)?;
+        } else {
+            steps.push(read_step(element)?);
+        }
+    }
+
+    close_section(&mut parent, &mut steps, sections);
+    Ok(())
+}
+
+/// Adds the run of steps to the sections, under the parent section when it has not been added
+/// yet.
+fn close_section(
+    parent: &mut Option<HowToSectionType>,
+    steps: &mut Vec<HowTo>,
+    sections: &mut Vec<HowToSectionType>,
+) {
+    match parent.take() {
+        Some(mut section) => {
+            section.item_list_element.append(steps);
+            sections.push(section);
+        }
+        None if !steps.is_empty() => sections.push(HowToSectionType {
+            at_type: AtType::HowToSection,
+            item_list_element: std::mem::take(steps),
+            ..Default::default()
+        }),
+        None => {}
+    }
+}
+
+/// Reads a step given as text, a HowToStep, or a ListItem wrapping either.
+fn read_step(value: serde_json::Value) -> Result<HowTo, serde_json::Error> {
+    let step = match value {
+        serde_json::Value::String(text) => HowTo {
+            at_type: AtType::HowToStep,
+            text,
+            ..Default::default()
+        },
+        serde_json::Value::Object(mut object) if has_type_in(&object, "ListItem") => {
+            match object.remove("item") {
+                Some(item) => return read_step(item),
+                None => {
+                    object.remove("position");
+                    object.insert("@type".to_string(), "HowToStep".into());
+                    serde_json::from_value(serde_json::Value::Object(object))?
+                }
+            }
+        }
+        value => serde_json::from_value(value)?,
+    };
+    Ok(trim_how_to(step))
+}
+
+/// Whether the `@type` of the object is, or includes, the type.
+fn has_type(value: &serde_json::Value, at_type: &str) -> bool {
+    value
+        .as_object()
+        .is_some_and(|object| has_type_in(object, at_type))
+}
+
+fn has_type_in(object: &serde_json::Map<String, serde_json::Value>, at_type: &str) -> bool {
+    match object.get("@type") {
+        Some(serde_json::Value::String(t)) => t == at_type,
+        Some(serde_json::Value::Array(types)) => types.iter().any(|t| t.as_str() == Some(at_type)),
+        _ => false,
+    }
+}
+
+/// The elements of an array, or the value itself when it is not one.
+fn into_values(value: serde_json::Value) -> Vec<serde_json::Value> {
+    match value {
+        serde_json::Value::Array(values) => values,
+        serde_json::Value::Null => Vec::new(),
+        value => vec![value],
+    }
+}
+
+fn trim_how_to(mut how_to: HowTo) -> HowTo {
+    how_to.name = how_to.name.map(deserialize_trim);
+    how_to.text = deserialize_trim(how_to.text);
+    how_to
+}
+
 fn deserialize_trim(mut s: String) -> String {
     let replace_map: HashMap<&str, &str> = HashMap::from_iter([("&nbsp;", " ")]);
     for (old, new) in replace_map.iter() {
@@ -413,7 +578,8 @@ fn deserialize_trim(mut s: String) -> String {
     s.trim().to_string()
 }
 
-#[derive(Debug, PartialEq)]
+#[derive(Debug, PartialEq, Serialize)]
+#[serde(untagged)]
 pub enum CreativeWorkOrText {
     CreativeWork(Box<CreativeWorkType>),
     Text(String),
@@ -462,7 +628,8 @@ impl<'de> Deserialize<'de> for CreativeWorkOrText {
     }
 }
 
-#[derive(Debug, PartialEq)]
+#[derive(Debug, PartialEq, Serialize)]
+#[serde(untagged)]
 pub enum CreativeWorkOrUrl {
     Url(Url),
     CreativeWork(Box<CreativeWorkType>),
@@ -518,7 +685,8 @@ impl<'de> Deserialize<'de> for CreativeWorkOrUrl {
 }
 
 /// The most generic kind of creative work, including books, movies, photographs, software programs, etc.
-#[derive(Debug, Default, Deserialize, PartialEq)]
+#[skip_serializing_none]
+#[derive(Debug, Default, Deserialize, Serialize, PartialEq)]
 pub struct CreativeWorkType {
     #[serde(rename = "@id")]
     pub at_id: Option<Url>,
@@ -541,7 +709,8 @@ fn set_creative_work_type() -> AtType {
     AtType::CreativeWork
 }
 
-#[derive(Debug, PartialEq)]
+#[derive(Debug, PartialEq, Serialize)]
+#[serde(untagged)]
 pub enum DateOrDateTime {
     DateTime(iso8601::DateTime),
     Date(iso8601::Date),
@@ -604,7 +773,8 @@ impl<'de> Deserialize<'de> for DateOrDateTime {
     }
 }
 
-#[derive(Debug, PartialEq)]
+#[derive(Debug, PartialEq, Serialize)]
+#[serde(untagged)]
 pub enum DefinedTermOrTextOrUrl {
     DefinedTerm(DefinedTermType),
     Text(String),
@@ -615,7 +785,7 @@ pub enum DefinedTermOrTextOrUrl {
 /// category or subject classification, glossaries or dictionaries, product or creative work types,
 /// etc. Use the name property for the term being defined, use termCode if the term has an
 /// alpha-numeric code allocated, use description to provide the definition of the term.
-#[derive(Debug, Deserialize, PartialEq)]
+#[derive(Debug, Deserialize, Serialize, PartialEq)]
 #[serde(deny_unknown_fields)]
 pub struct DefinedTermType {}
 
@@ -685,8 +855,20 @@ pub enum DistanceOrQuantitativeValue {
     QuantitativeValue(QuantitativeValueType),
 }
 
+impl Serialize for DistanceOrQuantitativeValue {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        match self {
+            DistanceOrQuantitativeValue::Distance(d) => serializer.serialize_str(&d.value),
+            DistanceOrQuantitativeValue::QuantitativeValue(q) => q.serialize(serializer),
+        }
+    }
+}
+
 /// Properties that take Distances as values are of the form '<Number> <Length unit of measure>'. E.g., '7 ft'.
-#[derive(Debug, Default, Deserialize, PartialEq)]
+#[derive(Debug, Default, Deserialize, Serialize, PartialEq)]
 #[serde(deny_unknown_fields)]
 pub struct DistanceType {
     pub value: String,
@@ -745,12 +927,26 @@ impl<'de> Deserialize<'de> for DistanceOrQuantitativeValue {
 
 /// A sub-grouping of steps in the instructions for how to achieve a result (e.g. steps for
 /// making a pie crust within a pie recipe).
-#[derive(Debug, Default, Deserialize, PartialEq)]
-#[serde(deny_unknown_fields)]
-pub struct HowToSectionType {}
+///
+/// The other properties of the section, e.g. its `position` or `@id`, are ignored.
+#[skip_serializing_none]
+#[derive(Debug, Default, Deserialize, Serialize, PartialEq)]
+#[serde(rename_all = "camelCase")]
+pub struct HowToSectionType {
+    #[serde(rename = "@type")]
+    pub at_type: AtType,
+
+    /// The heading of the section, e.g. "For the sauce".
+    pub name: Option<String>,
+
+    /// The steps of the section.
+    #[serde(default)]
+    pub item_list_element: Vec<HowTo>,
+}
 
 /// Instructions that explain how to achieve a result by performing a sequence of steps.
-#[derive(Debug, Default, Deserialize, PartialEq)]
+#[skip_serializing_none]
+#[derive(Debug, Default, Deserialize, Serialize, PartialEq)]
 #[serde(deny_unknown_fields)]
 pub struct HowTo {
     #[serde(rename = "@type")]
@@ -761,14 +957,15 @@ pub struct HowTo {
     pub image: Option<ImageObjectOrUrl>,
 }
 
-#[derive(Debug, PartialEq)]
+#[derive(Debug, PartialEq, Serialize)]
+#[serde(untagged)]
 pub enum HowToSupplyOrText {
     HowToSupply(HowToSupplyType),
     Text(String),
 }
 
 /// A sub-property of instrument. A supply consumed when performing instructions or a direction.
-#[derive(Debug, Deserialize, PartialEq)]
+#[derive(Debug, Deserialize, Serialize, PartialEq)]
 #[serde(deny_unknown_fields)]
 pub struct HowToSupplyType {}
 
@@ -815,14 +1012,15 @@ impl<'de> Deserialize<'de> for HowToSupplyOrText {
     }
 }
 
-#[derive(Debug, PartialEq)]
+#[derive(Debug, PartialEq, Serialize)]
+#[serde(untagged)]
 pub enum HowToToolOrText {
     HowToTool(HowToToolType),
     Text(String),
 }
 
 /// A tool used (but not consumed) when performing instructions for how to achieve a result.
-#[derive(Debug, Deserialize, PartialEq)]
+#[derive(Debug, Deserialize, Serialize, PartialEq)]
 #[serde(deny_unknown_fields)]
 pub struct HowToToolType {}
 
@@ -869,14 +1067,19 @@ impl<'de> Deserialize<'de> for HowToToolOrText {
     }
 }
 
-#[derive(Debug, PartialEq)]
+#[derive(Debug, PartialEq, Serialize)]
+#[serde(untagged)]
 pub enum ImageObjectOrUrl {
     Url(Url),
     ImageObject(Box<ImageObjectType>),
+
+    /// The same image at different sizes or aspect ratios, or several images.
+    Images(Vec<ImageObjectOrUrl>),
 }
 
 /// An image file.
-#[derive(Debug, Default, Deserialize, PartialEq)]
+#[skip_serializing_none]
+#[derive(Debug, Default, Deserialize, Serialize, PartialEq)]
 #[serde(deny_unknown_fields, rename_all = "camelCase")]
 pub struct ImageObjectType {
     #[serde(rename = "@type", default = "set_image_object")]
@@ -958,18 +1161,25 @@ impl<'de> Deserialize<'de> for ImageObjectOrUrl {
             where
                 A: SeqAccess<'de>,
             {
-                let mut vec: Vec<String> = Vec::new();
-                while let Some(s) = seq.next_element()? {
-                    vec.push(s)
+                // The images that cannot be read are skipped rather than failing the others.
+                let mut vec: Vec<ImageObjectOrUrl> = Vec::new();
+                let mut error = None;
+                while let Some(value) = seq.next_element::<serde_json::Value>()? {
+                    match ImageObjectOrUrl::deserialize(value) {
+                        Ok(image) => vec.push(image),
+                        Err(ex) => error = Some(ex),
+                    }
                 }
 
-                let v = match vec.pop() {
-                    None => return Err(Error::custom("sequence is empty")),
-                    Some(v) => v,
-                };
+                if let (true, Some(ex)) = (vec.is_empty(), error) {
+                    return Err(Error::custom(ex));
+                }
 
-                let url = url::Url::parse(&v).map_err(|ex| Error::custom(ex.to_string()))?;
-                Ok(Url(url))
+                match vec.len() {
+                    0 => Err(Error::custom("sequence is empty")),
+                    1 => Ok(vec.remove(0)),
+                    _ => Ok(Images(vec)),
+                }
             }
 
             fn visit_map<A>(self, map: A) -> Result<Self::Value, A::Error>
@@ -985,7 +1195,8 @@ impl<'de> Deserialize<'de> for ImageObjectOrUrl {
     }
 }
 
-#[derive(Debug, PartialEq)]
+#[derive(Debug, PartialEq, Serialize)]
+#[serde(untagged)]
 pub enum IntegerOrText {
     Integer(i64),
     Text(String),
@@ -1040,14 +1251,16 @@ impl<'de> Deserialize<'de> for IntegerOrText {
     }
 }
 
-#[derive(Debug, PartialEq)]
+#[derive(Debug, PartialEq, Serialize)]
+#[serde(untagged)]
 pub enum ListItemOrTextOrThing {
     ListItem(Vec<ListItemType>),
     Text(String),
     Thing(ThingType),
 }
 
-#[derive(Debug, Default, Deserialize, PartialEq)]
+#[skip_serializing_none]
+#[derive(Debug, Default, Deserialize, Serialize, PartialEq)]
 #[serde(deny_unknown_fields)]
 pub struct ListItemType {
     #[serde(rename = "@type", default = "set_list_item_type")]
@@ -1121,7 +1334,8 @@ impl<'de> Deserialize<'de> for ListItemOrTextOrThing {
     }
 }
 
-#[derive(Debug, PartialEq)]
+#[derive(Debug, PartialEq, Serialize)]
+#[serde(untagged)]
 pub enum LanguageOrText {
     Language(LanguageType),
     Text(String),
@@ -1131,7 +1345,7 @@ pub enum LanguageOrText {
 /// expressed in BCP 47 can be used via the alternateName property. The Language type previously
 /// also covered programming languages such as Scheme and Lisp, which are now best represented
 /// using ComputerLanguage.
-#[derive(Debug, Default, Deserialize, PartialEq)]
+#[derive(Debug, Default, Deserialize, Serialize, PartialEq)]
 #[serde(deny_unknown_fields)]
 pub struct LanguageType {}
 
@@ -1178,7 +1392,8 @@ impl<'de> Deserialize<'de> for LanguageOrText {
     }
 }
 
-#[derive(Debug, PartialEq)]
+#[derive(Debug, PartialEq, Serialize)]
+#[serde(untagged)]
 pub enum MediaObjectOrText {
     MediaObject(MediaObjectType),
     Text(String),
@@ -1188,7 +1403,7 @@ pub enum MediaObjectOrText {
 /// downloadable dataset i.e. DataDownload. Note that a creative work may have many media objects
 /// associated with it on the same web page. For example, a page about a single song (MusicRecording)
 /// may have a music video (VideoObject), and a high and low bandwidth audio stream (2 AudioObject's).
-#[derive(Debug, Default, Deserialize, PartialEq)]
+#[derive(Debug, Default, Deserialize, Serialize, PartialEq)]
 #[serde(deny_unknown_fields)]
 pub struct MediaObjectType {}
 
@@ -1236,7 +1451,8 @@ impl<'de> Deserialize<'de> for MediaObjectOrText {
     }
 }
 
-#[derive(Debug, PartialEq)]
+#[derive(Debug, PartialEq, Serialize)]
+#[serde(untagged)]
 pub enum MonetaryAmountOrText {
     MonetaryAmount(MonetaryAmountType),
     Text(String),
@@ -1246,7 +1462,7 @@ pub enum MonetaryAmountOrText {
 /// $50 USD, or a range as in describing a bank account being suitable for a balance between
 /// £1,000 and £1,000,000 GBP, or the value of a salary, etc. It is recommended to use PriceSpecification
 /// Types to describe the price of an Offer, Invoice, etc.
-#[derive(Debug, Deserialize, PartialEq)]
+#[derive(Debug, Deserialize, Serialize, PartialEq)]
 #[serde(deny_unknown_fields)]
 pub struct MonetaryAmountType {}
 
@@ -1294,7 +1510,8 @@ impl<'de> Deserialize<'de> for MonetaryAmountOrText {
     }
 }
 
-#[derive(Debug, PartialEq)]
+#[derive(Debug, PartialEq, Serialize)]
+#[serde(untagged)]
 pub enum NumberOrText {
     Number(i64),
     Text(String),
@@ -1349,7 +1566,8 @@ impl<'de> Deserialize<'de> for NumberOrText {
     }
 }
 
-#[derive(Debug, PartialEq)]
+#[derive(Debug, PartialEq, Serialize)]
+#[serde(untagged)]
 pub enum OrganizationOrPerson {
     Organization(OrganizationType),
     Person(PersonType),
@@ -1362,7 +1580,8 @@ impl Default for OrganizationOrPerson {
 }
 
 /// An organization such as a school, NGO, corporation, club, etc.
-#[derive(Debug, Default, Deserialize, PartialEq)]
+#[skip_serializing_none]
+#[derive(Debug, Default, Deserialize, Serialize, PartialEq)]
 #[serde(deny_unknown_fields)]
 pub struct OrganizationType {
     #[serde(rename = "@id")]
@@ -1381,7 +1600,8 @@ pub struct OrganizationType {
 }
 
 /// A person (alive, dead, undead, or fictional).
-#[derive(Debug, Default, Deserialize, PartialEq)]
+#[skip_serializing_none]
+#[derive(Debug, Default, Deserialize, Serialize, PartialEq)]
 #[serde(deny_unknown_fields)]
 pub struct PersonType {
     #[serde(rename = "@id")]
@@ -1432,18 +1652,19 @@ impl<'de> Deserialize<'de> for OrganizationOrPerson {
     }
 }
 
-#[derive(Debug, Deserialize, PartialEq)]
+#[derive(Debug, Deserialize, Serialize, PartialEq)]
 #[serde(deny_unknown_fields)]
 pub struct PlaceType {}
 
-#[derive(Debug, PartialEq)]
+#[derive(Debug, PartialEq, Serialize)]
+#[serde(untagged)]
 pub enum PropertyValueOrTextOrUrl {
     PropertyValue(PropertyValueType),
     Text(String),
     Url(Url),
 }
 
-#[derive(Debug, Deserialize, PartialEq)]
+#[derive(Debug, Deserialize, Serialize, PartialEq)]
 #[serde(deny_unknown_fields)]
 pub struct PropertyValueType {}
 
@@ -1497,7 +1718,8 @@ impl<'de> Deserialize<'de> for PropertyValueOrTextOrUrl {
     }
 }
 
-#[derive(Debug, PartialEq)]
+#[derive(Debug, PartialEq, Serialize)]
+#[serde(untagged)]
 pub enum QuantitativeValueOrText {
     QuantitativeValue(QuantitativeValueType),
     Text(String),
@@ -1510,7 +1732,8 @@ impl Default for QuantitativeValueOrText {
 }
 
 /// A point value or interval for product characteristics and other purposes.
-#[derive(Debug, PartialEq)]
+#[derive(Debug, PartialEq, Serialize)]
+#[serde(transparent)]
 pub struct QuantitativeValueType {
     pub value: i64,
 }
@@ -1545,6 +1768,17 @@ impl<'de> Deserialize<'de> for QuantitativeValueOrText {
                 Ok(QuantitativeValue(QuantitativeValueType { value: v as i64 }))
             }
 
+            fn visit_f64<E>(self, v: f64) -> Result<Self::Value, E>
+            where
+                E: Error,
+            {
+                if v.fract() == 0.0 {
+                    Ok(QuantitativeValue(QuantitativeValueType { value: v as i64 }))
+                } else {
+                    Ok(Text(v.to_string()))
+                }
+            }
+
             fn visit_str<E>(self, v: &str) -> Result<Self::Value, E>
             where
                 E: Error,
@@ -1564,15 +1798,24 @@ impl<'de> Deserialize<'de> for QuantitativeValueOrText {
                 A: SeqAccess<'de>,
             {
                 let mut vec: Vec<String> = Vec::new();
-                while let Some(item) = seq.next_element::<String>()? {
-                    vec.push(item);
+                while let Some(item) = seq.next_element::<serde_json::Value>()? {
+                    match item {
+                        serde_json::Value::String(s) => vec.push(s),
+                        serde_json::Value::Number(n) => vec.push(n.to_string()),
+                        _ => return Err(Error::custom("expected a number or text")),
+                    }
                 }
 
+                // A yield such as ["4", "4 servings"] is best described by its text.
                 let v = vec
-                    .first()
+                    .iter()
+                    .find(|v| v.chars().any(char::is_alphabetic))
+                    .or_else(|| vec.first())
                     .ok_or_else(|| Error::custom("sequence is empty"))?;
-                let v: i64 = v.parse().map_err(Error::custom)?;
-                Ok(QuantitativeValue(QuantitativeValueType { value: v }))
+                match v.trim().parse() {
+                    Ok(v) => Ok(QuantitativeValue(QuantitativeValueType { value: v })),
+                    Err(_) => Ok(Text(v.to_owned())),
+                }
             }
         }
 
@@ -1580,14 +1823,15 @@ impl<'de> Deserialize<'de> for QuantitativeValueOrText {
     }
 }
 
-#[derive(Debug, PartialEq)]
+#[derive(Debug, PartialEq, Serialize)]
+#[serde(untagged)]
 pub enum RatingOrText {
     Rating(RatingType),
     Text(String),
 }
 
 /// A rating is an evaluation on a numeric scale, such as 1 to 5 stars.
-#[derive(Debug, Deserialize, PartialEq)]
+#[derive(Debug, Deserialize, Serialize, PartialEq)]
 #[serde(deny_unknown_fields)]
 pub struct RatingType {}
 
@@ -1634,7 +1878,8 @@ impl<'de> Deserialize<'de> for RatingOrText {
     }
 }
 
-#[derive(Debug, Default, PartialEq, Deserialize)]
+#[skip_serializing_none]
+#[derive(Debug, Default, PartialEq, Deserialize, Serialize)]
 pub struct ReviewType {
     #[serde(rename = "@type")]
     pub at_type: AtType,
@@ -1647,7 +1892,7 @@ pub struct ReviewType {
     pub review_body: Option<String>,
 }
 
-#[derive(Debug, Default, PartialEq, Deserialize)]
+#[derive(Debug, Default, PartialEq, Deserialize, Serialize)]
 pub struct ReviewRating {
     #[serde(rename = "@type")]
     pub at_type: AtType,
@@ -1655,14 +1900,15 @@ pub struct ReviewRating {
     pub rating_value: String,
 }
 
-#[derive(Debug, PartialEq)]
+#[derive(Debug, PartialEq, Serialize)]
+#[serde(untagged)]
 pub enum TextOrTextObject {
     Text(String),
     TextObject(TextObjectType),
 }
 
 /// A text file. The text can be unformatted or contain markup, html, etc.
-#[derive(Debug, Default, Deserialize, PartialEq)]
+#[derive(Debug, Default, Deserialize, Serialize, PartialEq)]
 #[serde(deny_unknown_fields)]
 pub struct TextObjectType {}
 
@@ -1709,14 +1955,15 @@ impl<'de> Deserialize<'de> for TextOrTextObject {
     }
 }
 
-#[derive(Debug, PartialEq)]
+#[derive(Debug, PartialEq, Serialize)]
+#[serde(untagged)]
 pub enum ThingOrUrl {
     Thing(ThingType),
     Url(Url),
 }
 
 /// The most generic type of item.
-#[derive(Debug, Default, Deserialize, PartialEq)]
+#[derive(Debug, Default, Deserialize, Serialize, PartialEq)]
 #[serde(deny_unknown_fields)]
 pub struct ThingType {}
 
@@ -1769,7 +2016,8 @@ impl<'de> Deserialize<'de> for ThingOrUrl {
     }
 }
 
-#[derive(Debug, PartialEq)]
+#[derive(Debug, PartialEq, Serialize)]
+#[serde(untagged)]
 pub enum Video {
     VideoObject(VideoObjectType),
 }
@@ -1804,50 +2052,35 @@ impl<'de> Deserialize<'de> for Video {
 }
 
 /// A video file.
-#[derive(Debug, Deserialize, PartialEq)]
+#[skip_serializing_none]
+#[derive(Debug, Deserialize, Serialize, PartialEq)]
 #[serde(deny_unknown_fields, rename_all = "camelCase")]
 pub struct VideoObjectType {
     #[serde(rename = "@type")]
     pub at_type: AtType,
 
     /// Actual bytes of the media object, for example the image file or video file.
-    pub content_url: Url,
+    pub content_url: Option<Url>,
 
     /// A description of the item.
     pub description: String,
 
     /// Approximate or typical time it usually takes to work with or through the content of this
     /// work for the typical or target audience.
-    #[serde(deserialize_with = "deserialize_duration")]
+    #[serde(default, deserialize_with = "crate::duration::deserialize_lenient")]
     pub duration: Option<iso8601::Duration>,
 
     /// A URL pointing to a player for a specific video. In general, this is the information in
     /// the src element of an embed tag and should not be the same as the content of the loc tag.
-    pub embed_url: Url,
+    pub embed_url: Option<Url>,
 
     /// The name of the item.
     pub name: String,
 
     /// A thumbnail image relevant to the Thing.
+    #[serde(default)]
     pub thumbnail_url: Vec<Url>,
 
     /// Date (including time if available) when this media object was uploaded to this site.
     pub upload_date: Option<iso8601::DateTime>,
 }
-
-fn deserialize_duration<'de, D>(deserializer: D) -> Result<Option<iso8601::Duration>, D::Error>
-where
-    D: Deserializer<'de>,
-{
-    let s: Option<String> = Option::deserialize(deserializer)?;
-    match s {
-        Some(s) => {
-            if s.is_empty() {
-                return Ok(None);
-            }
-            let (_, dur) = iso8601::parsers::parse_duration(s.as_bytes()).map_err(Error::custom)?;
-            Ok(Some(dur))
-        }
-        None => Ok(None),
-    }
-}
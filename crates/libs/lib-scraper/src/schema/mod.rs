@@ -1,4 +1,4 @@
-use serde::{de, de::SeqAccess, Deserialize, Deserializer};
+use serde::{de, de::SeqAccess, Deserialize, Deserializer, Serialize, Serializer};
 
 pub mod article;
 pub mod common;
@@ -28,14 +28,27 @@ impl<'de> Deserialize<'de> for AtContext {
     }
 }
 
+impl Serialize for AtContext {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        match self {
+            AtContext::SchemaDotOrg => serializer.serialize_str("https://schema.org"),
+        }
+    }
+}
+
 #[derive(Debug, Clone, PartialEq)]
 pub enum AtType {
     AggregateRating,
     Article,
     BreadcrumbList,
     CreativeWork,
+    HowToSection,
     HowToStep,
     ImageObject,
+    ItemList,
     ListItem,
     NewsArticle,
     NutritionInformation,
@@ -78,8 +91,11 @@ impl<'de> Deserialize<'de> for AtType {
                     "AggregateRating" => Ok(AtType::AggregateRating),
                     "Article" => Ok(AtType::Article),
                     "BreadcrumbList" => Ok(AtType::BreadcrumbList),
+                    "CreativeWork" => Ok(AtType::CreativeWork),
+                    "HowToSection" => Ok(AtType::HowToSection),
                     "HowToStep" => Ok(AtType::HowToStep),
                     "ImageObject" => Ok(AtType::ImageObject),
+                    "ItemList" => Ok(AtType::ItemList),
                     "ListItem" => Ok(AtType::ListItem),
                     "NewsArticle" => Ok(AtType::NewsArticle),
                     "NutritionInformation" => Ok(AtType::NutritionInformation),
@@ -110,3 +126,36 @@ impl<'de> Deserialize<'de> for AtType {
         deserializer.deserialize_any(Visitor)
     }
 }
+
+impl Serialize for AtType {
+    /// Writes the name of the type. An unspecified type is written as the most generic
+    /// schema.org type, `Thing`.
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        let name = match self {
+            AtType::AggregateRating => "AggregateRating",
+            AtType::Article => "Article",
+            AtType::BreadcrumbList => "BreadcrumbList",
+            AtType::CreativeWork => "CreativeWork",
+            AtType::HowToSection => "HowToSection",
+            AtType::HowToStep => "HowToStep",
+            AtType::ImageObject => "ImageObject",
+            AtType::ItemList => "ItemList",
+            AtType::ListItem => "ListItem",
+            AtType::NewsArticle => "NewsArticle",
+            AtType::NutritionInformation => "NutritionInformation",
+            AtType::Organization => "Organization",
+            AtType::Person => "Person",
+            AtType::Recipe => "Recipe",
+            AtType::Review => "Review",
+            AtType::Rating => "Rating",
+            AtType::VideoObject => "VideoObject",
+            AtType::Unspecified => "Thing",
+            AtType::WebPage => "WebPage",
+            AtType::WebSite => "WebSite",
+        };
+        serializer.serialize_str(name)
+    }
+}
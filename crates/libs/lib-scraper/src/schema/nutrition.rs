@@ -1,11 +1,13 @@
-use std::fmt::Formatter;
-
-use serde::{de, Deserialize, Deserializer};
+use regex::Regex;
+use serde::{de, Deserialize, Deserializer, Serialize, Serializer};
+use serde_with::skip_serializing_none;
+use std::{fmt::Formatter, sync::OnceLock};
 
 use crate::schema::AtType;
 
 /// Nutritional information about the recipe as described in the [schema](https://schema.org/NutritionInformation).
-#[derive(Debug, Default, Deserialize, PartialEq)]
+#[skip_serializing_none]
+#[derive(Debug, Default, Deserialize, Serialize, PartialEq)]
 #[serde(deny_unknown_fields, rename_all = "camelCase")]
 pub struct NutritionInformationSchema {
     #[serde(rename = "@type")]
@@ -37,11 +39,73 @@ pub struct NutritionInformationSchema {
 }
 
 /// Properties that take Energy as values are of the form '<Number> <Energy unit of measure>'.
+///
+/// Values whose number cannot be read are kept as [`Energy::Str`].
 #[derive(Debug, PartialEq)]
 pub enum Energy {
+    Value(f64, EnergyUnit),
     Str(String),
 }
 
+/// A unit of measure of energy.
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum EnergyUnit {
+    Kilocalorie,
+    Kilojoule,
+}
+
+const KILOJOULES_PER_KILOCALORIE: f64 = 4.184;
+
+impl Energy {
+    /// Reads values such as "250 kcal", "1046 kJ" or "240 calories". A number without a unit
+    /// is in kilocalories.
+    pub fn parse(s: &str) -> Self {
+        let Some((value, unit)) = parse_number_and_unit(s) else {
+            return Energy::Str(s.to_string());
+        };
+
+        let unit = match unit.as_str() {
+            "" | "cal" | "cals" | "calorie" | "calories" | "kcal" | "kcals" | "kilocalorie"
+            | "kilocalories" => EnergyUnit::Kilocalorie,
+            "kj" | "kilojoule" | "kilojoules" => EnergyUnit::Kilojoule,
+            _ => return Energy::Str(s.to_string()),
+        };
+        Energy::Value(value, unit)
+    }
+
+    /// The energy in kilocalories.
+    pub fn kcal(&self) -> Option<f64> {
+        match self {
+            Energy::Value(v, EnergyUnit::Kilocalorie) => Some(*v),
+            Energy::Value(v, EnergyUnit::Kilojoule) => Some(v / KILOJOULES_PER_KILOCALORIE),
+            Energy::Str(_) => None,
+        }
+    }
+
+    /// The energy in kilojoules.
+    pub fn kilojoules(&self) -> Option<f64> {
+        self.kcal().map(|v| v * KILOJOULES_PER_KILOCALORIE)
+    }
+}
+
+impl Serialize for Energy {
+    /// Writes values such as "250 kcal" or "1046 kJ".
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        match self {
+            Energy::Value(v, EnergyUnit::Kilocalorie) => {
+                serializer.collect_str(&format_args!("{v} kcal"))
+            }
+            Energy::Value(v, EnergyUnit::Kilojoule) => {
+                serializer.collect_str(&format_args!("{v} kJ"))
+            }
+            Energy::Str(s) => serializer.serialize_str(s),
+        }
+    }
+}
+
 impl<'de> Deserialize<'de> for Energy {
     fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
     where
@@ -55,34 +119,130 @@ impl<'de> Deserialize<'de> for Energy {
             type Value = Energy;
 
             fn expecting(&self, formatter: &mut Formatter) -> std::fmt::Result {
-                formatter.write_str("a string representing an energy value")
+                formatter.write_str("a string or number representing an energy value")
             }
 
             fn visit_str<E>(self, v: &str) -> Result<Self::Value, E>
             where
                 E: de::Error,
             {
-                Ok(Str(v.to_string()))
+                Ok(Energy::parse(v))
+            }
+
+            fn visit_f64<E>(self, v: f64) -> Result<Self::Value, E>
+            where
+                E: de::Error,
+            {
+                Ok(Value(v, EnergyUnit::Kilocalorie))
+            }
+
+            fn visit_i64<E>(self, v: i64) -> Result<Self::Value, E>
+            where
+                E: de::Error,
+            {
+                self.visit_f64(v as f64)
             }
 
-            fn visit_string<E>(self, v: String) -> Result<Self::Value, E>
+            fn visit_u64<E>(self, v: u64) -> Result<Self::Value, E>
             where
                 E: de::Error,
             {
-                Ok(Str(v))
+                self.visit_f64(v as f64)
             }
         }
 
-        deserializer.deserialize_str(Visitor)
+        deserializer.deserialize_any(Visitor)
     }
 }
 
 /// Properties that take Mass as values are of the form '<Number> <Mass unit of measure>'. E.g., '7 kg'.
+///
+/// Values whose number cannot be read are kept as [`Mass::Str`].
 #[derive(Debug, PartialEq)]
 pub enum Mass {
+    Value(f64, MassUnit),
     Str(String),
 }
 
+/// A unit of measure of mass.
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum MassUnit {
+    Kilogram,
+    Gram,
+    Milligram,
+    Microgram,
+}
+
+impl MassUnit {
+    fn grams(&self) -> f64 {
+        match self {
+            MassUnit::Kilogram => 1_000.0,
+            MassUnit::Gram => 1.0,
+            MassUnit::Milligram => 0.001,
+            MassUnit::Microgram => 0.000_001,
+        }
+    }
+
+    fn symbol(&self) -> &'static str {
+        match self {
+            MassUnit::Kilogram => "kg",
+            MassUnit::Gram => "g",
+            MassUnit::Milligram => "mg",
+            MassUnit::Microgram => "mcg",
+        }
+    }
+}
+
+impl Mass {
+    /// Reads values such as "12.5g", "3.6 mg" or "9 grams fat". A number without a unit is
+    /// in grams.
+    pub fn parse(s: &str) -> Self {
+        let Some((value, unit)) = parse_number_and_unit(s) else {
+            return Mass::Str(s.to_string());
+        };
+
+        let unit = match unit.as_str() {
+            "kg" | "kgs" | "kilogram" | "kilograms" => MassUnit::Kilogram,
+            "" | "g" | "gr" | "gram" | "grams" => MassUnit::Gram,
+            "mg" | "milligram" | "milligrams" => MassUnit::Milligram,
+            "µg" | "μg" | "ug" | "mcg" | "microgram" | "micrograms" => MassUnit::Microgram,
+            _ => return Mass::Str(s.to_string()),
+        };
+        Mass::Value(value, unit)
+    }
+
+    /// The mass in the given unit.
+    pub fn to_unit(&self, unit: MassUnit) -> Option<f64> {
+        match self {
+            Mass::Value(v, from) => Some(v * from.grams() / unit.grams()),
+            Mass::Str(_) => None,
+        }
+    }
+
+    /// The mass in grams.
+    pub fn grams(&self) -> Option<f64> {
+        self.to_unit(MassUnit::Gram)
+    }
+
+    /// The mass in milligrams.
+    pub fn milligrams(&self) -> Option<f64> {
+        self.to_unit(MassUnit::Milligram)
+    }
+}
+
+impl Serialize for Mass {
+    /// Writes values such as "12.5 g" or "3.6 mg".
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        match self {
+            Mass::Value(v, unit) => serializer.collect_str(&format_args!("{v} {}", unit.symbol())),
+            Mass::Str(s) => serializer.serialize_str(s),
+        }
+    }
+}
+
 impl<'de> Deserialize<'de> for Mass {
     fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
     where
@@ -96,30 +256,71 @@ impl<'de> Deserialize<'de> for Mass {
             type Value = Mass;
 
             fn expecting(&self, formatter: &mut Formatter) -> std::fmt::Result {
-                formatter.write_str("a string representing an energy value")
+                formatter.write_str("a string or number representing a mass value")
             }
 
             fn visit_str<E>(self, v: &str) -> Result<Self::Value, E>
             where
                 E: de::Error,
             {
-                Ok(Str(v.to_string()))
+                Ok(Mass::parse(v))
             }
 
-            fn visit_string<E>(self, v: String) -> Result<Self::Value, E>
+            fn visit_f64<E>(self, v: f64) -> Result<Self::Value, E>
             where
                 E: de::Error,
             {
-                Ok(Str(v))
+                Ok(Value(v, MassUnit::Gram))
+            }
+
+            fn visit_i64<E>(self, v: i64) -> Result<Self::Value, E>
+            where
+                E: de::Error,
+            {
+                self.visit_f64(v as f64)
+            }
+
+            fn visit_u64<E>(self, v: u64) -> Result<Self::Value, E>
+            where
+                E: de::Error,
+            {
+                self.visit_f64(v as f64)
             }
         }
 
-        deserializer.deserialize_str(Visitor)
+        deserializer.deserialize_any(Visitor)
     }
 }
 
+/// Rounds a nutrient amount to fit in a `SMALLINT` column of the nutrition table.
+pub fn to_smallint(value: f64) -> i16 {
+    value.round().clamp(i16::MIN as f64, i16::MAX as f64) as i16
+}
+
+/// Splits values such as "1,046 kJ", "12.5g" or "< 1 g" into the number and the lowercase
+/// unit. Words after the unit, such as "fat" in "9 grams fat", are ignored.
+fn parse_number_and_unit(s: &str) -> Option<(f64, String)> {
+    static INSTANCE: OnceLock<Regex> = OnceLock::new();
+
+    let re = INSTANCE.get_or_init(|| {
+        Regex::new(
+            r"^[<>~≈]?\s*(?:(?<thousands>\d{1,3}(?:,\d{3})+(?:\.\d+)?)|(?<decimal>\d+(?:[.,]\d+)?))\s*(?<unit>[^\s\d.,()/]*)",
+        )
+        .unwrap()
+    });
+
+    let caps = re.captures(s.trim())?;
+    let number = match (caps.name("thousands"), caps.name("decimal")) {
+        (Some(n), _) => n.as_str().replace(',', ""),
+        (None, Some(n)) => n.as_str().replace(',', "."),
+        (None, None) => return None,
+    };
+
+    Some((number.parse().ok()?, caps["unit"].to_lowercase()))
+}
+
 /// A diet restricted to certain foods or preparations for cultural, religious, health or lifestyle reasons.
-#[derive(Debug, Default, PartialEq)]
+#[derive(Debug, Default, PartialEq, Serialize)]
 pub enum RestrictedDiet {
     DiabeticDiet,
     GlutenFreeDiet,
@@ -136,6 +337,12 @@ pub enum RestrictedDiet {
     UnspecifiedDiet,
 }
 
+impl RestrictedDiet {
+    pub(crate) fn is_unspecified(&self) -> bool {
+        *self == RestrictedDiet::UnspecifiedDiet
+    }
+}
+
 impl<'de> Deserialize<'de> for RestrictedDiet {
     fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
     where
@@ -14,28 +14,31 @@ use crate::schema::{
     AtContext, AtType,
 };
 use reqwest::Url;
-use serde::{de, Deserialize, Deserializer};
+use serde::{de, Deserialize, Deserializer, Serialize, Serializer};
+use serde_with::skip_serializing_none;
 
-#[derive(Debug, Deserialize, PartialEq)]
+#[derive(Debug, Deserialize, Serialize, PartialEq)]
 #[serde(untagged)]
 pub enum GraphObject {
     Recipe(Box<RecipeSchema>),
     Unknown(UnknownType),
 }
 
-#[derive(Debug, Deserialize, PartialEq)]
+#[derive(Debug, Deserialize, Serialize, PartialEq)]
 pub struct UnknownType {}
 
 /// The recipe schema as described in the [schema](https://schema.org/Recipe).
-#[derive(Debug, Default, Deserialize, PartialEq)]
+#[skip_serializing_none]
+#[derive(Debug, Default, Deserialize, Serialize, PartialEq)]
 #[serde(deny_unknown_fields, rename_all = "camelCase")]
 pub struct RecipeSchema {
     /// The context of the JSON.
     #[serde(rename = "@context", default)]
     pub at_context: AtContext,
 
-    /// The type of schema object.
-    #[serde(rename = "@type")]
+    /// The type of schema object. Written as `Recipe` when missing.
+    #[serde(rename = "@type", serialize_with = "serialize_recipe_type")]
+    #[serialize_always]
     pub at_type: Option<AtType>,
 
     #[serde(rename = "@graph")]
@@ -87,7 +90,11 @@ pub struct RecipeSchema {
     pub contributor: Option<OrganizationOrPerson>,
 
     /// The time it takes to actually cook the dish, in ISO 8601 duration format.
-    #[serde(alias = "CookTime")]
+    #[serde(
+        alias = "CookTime",
+        default,
+        deserialize_with = "crate::duration::deserialize_lenient"
+    )]
     pub cook_time: Option<iso8601::Duration>,
 
     /// The method of cooking, such as Frying, Steaming, etc.
@@ -140,7 +147,11 @@ pub struct RecipeSchema {
     pub in_language: Option<LanguageOrText>,
 
     /// A flag to signal that the item, event, or place is accessible for free. Supersedes free.
-    #[serde(default, deserialize_with = "deserialize_bool")]
+    #[serde(
+        default,
+        deserialize_with = "deserialize_bool",
+        skip_serializing_if = "std::ops::Not::not"
+    )]
     pub is_accessible_for_free: bool,
 
     /// Indicates an item or CreativeWork that this item, or CreativeWork (in some sense), is part of.
@@ -169,6 +180,7 @@ pub struct RecipeSchema {
 
     /// The length of time it takes to perform instructions or a direction (not including time to
     /// prepare the supplies), in ISO 8601 duration format.
+    #[serde(default, deserialize_with = "crate::duration::deserialize_lenient")]
     pub perform_time: Option<iso8601::Duration>,
 
     /// Indicates a potential Action, which describes an idealized action in which this thing
@@ -177,17 +189,22 @@ pub struct RecipeSchema {
 
     /// The length of time it takes to prepare the items to be used in instructions or a
     /// direction, in ISO 8601 duration format.
-    #[serde(alias = "PrepTime")]
+    #[serde(
+        alias = "PrepTime",
+        default,
+        deserialize_with = "crate::duration::deserialize_lenient"
+    )]
     pub prep_time: Option<iso8601::Duration>,
 
     /// The publisher of the creative work.
     pub publisher: Option<OrganizationOrPerson>,
 
     /// The category of the recipe—for example, appetizer, entree, etc.
-    #[serde(default)]
+    #[serde(default, skip_serializing_if = "RecipeCategory::is_uncategorized")]
     pub recipe_category: RecipeCategory,
 
     /// The cuisine of the recipe (for example, French or Ethiopian).
+    #[serde(default, deserialize_with = "deserialize_cuisine")]
     pub recipe_cuisine: Option<RecipeCuisine>,
 
     /// A step in making the recipe, in the form of a single item (document, video, etc.)
@@ -216,7 +233,7 @@ pub struct RecipeSchema {
 
     /// Indicates a dietary restriction or guideline for which this recipe or menu item
     /// is suitable, e.g. diabetic, halal etc.
-    #[serde(default)]
+    #[serde(default, skip_serializing_if = "RestrictedDiet::is_unspecified")]
     pub suitable_for_diet: RestrictedDiet,
 
     /// A sub-property of instrument. A supply consumed when performing instructions or a direction.
@@ -231,6 +248,7 @@ pub struct RecipeSchema {
 
     /// The total time required to perform instructions or a direction (including time to prepare
     /// the supplies), in ISO 8601 duration format.
+    #[serde(default, deserialize_with = "crate::duration::deserialize_lenient")]
     pub total_time: Option<iso8601::Duration>,
 
     /// The quantity that results by performing instructions. For example, a paper airplane,
@@ -264,6 +282,16 @@ pub struct RecipeSchema {
     pub work_translation: Option<CreativeWorkType>,
 }
 
+fn serialize_recipe_type<S>(at_type: &Option<AtType>, serializer: S) -> Result<S::Ok, S::Error>
+where
+    S: Serializer,
+{
+    at_type
+        .as_ref()
+        .unwrap_or(&AtType::Recipe)
+        .serialize(serializer)
+}
+
 fn deserialize_bool<'de, D>(deserializer: D) -> Result<bool, D::Error>
 where
     D: Deserializer<'de>,
@@ -302,14 +330,37 @@ where
     deserializer.deserialize_any(Visitor)
 }
 
-#[derive(Debug, PartialEq)]
+/// The categories of a recipe, e.g. "Dinner, Main Course, Pasta".
+#[derive(Debug, PartialEq, Serialize)]
+#[serde(untagged)]
 pub enum RecipeCategory {
-    Text(String),
+    Texts(Vec<String>),
 }
 
 impl Default for RecipeCategory {
     fn default() -> Self {
-        RecipeCategory::Text("uncategorized".to_string())
+        RecipeCategory::Texts(vec!["uncategorized".to_string()])
+    }
+}
+
+impl RecipeCategory {
+    pub(crate) fn from_terms(terms: Vec<String>) -> Self {
+        if terms.is_empty() {
+            Self::default()
+        } else {
+            RecipeCategory::Texts(terms)
+        }
+    }
+
+    /// The terms, trimmed and without duplicates.
+    pub fn values(&self) -> &[String] {
+        match self {
+            RecipeCategory::Texts(v) => v,
+        }
+    }
+
+    fn is_uncategorized(&self) -> bool {
+        *self == Self::default()
     }
 }
 
@@ -318,8 +369,6 @@ impl<'de> Deserialize<'de> for RecipeCategory {
     where
         D: Deserializer<'de>,
     {
-        use crate::schema::recipe::RecipeCategory::Text;
-
         struct Visitor;
 
         impl<'de> de::Visitor<'de> for Visitor {
@@ -333,14 +382,7 @@ impl<'de> Deserialize<'de> for RecipeCategory {
             where
                 E: de::Error,
             {
-                Ok(Text(v.to_owned()))
-            }
-
-            fn visit_string<E>(self, v: String) -> Result<Self::Value, E>
-            where
-                E: de::Error,
-            {
-                Ok(Text(v))
+                Ok(RecipeCategory::from_terms(split_terms([v])))
             }
 
             fn visit_seq<A>(self, mut seq: A) -> Result<Self::Value, A::Error>
@@ -351,12 +393,9 @@ impl<'de> Deserialize<'de> for RecipeCategory {
                 while let Some(item) = seq.next_element::<String>()? {
                     vec.push(item);
                 }
-
-                let v = vec
-                    .first()
-                    .ok_or_else(|| de::Error::custom("sequence is empty"))?;
-
-                Ok(Text(v.to_string()))
+                Ok(RecipeCategory::from_terms(split_terms(
+                    vec.iter().map(String::as_str),
+                )))
             }
         }
 
@@ -364,9 +403,20 @@ impl<'de> Deserialize<'de> for RecipeCategory {
     }
 }
 
-#[derive(Debug, PartialEq)]
+/// The cuisines of a recipe, e.g. "French, Ethiopian".
+#[derive(Debug, PartialEq, Serialize)]
+#[serde(untagged)]
 pub enum RecipeCuisine {
-    Text(String),
+    Texts(Vec<String>),
+}
+
+impl RecipeCuisine {
+    /// The terms, trimmed and without duplicates.
+    pub fn values(&self) -> &[String] {
+        match self {
+            RecipeCuisine::Texts(v) => v,
+        }
+    }
 }
 
 impl<'de> Deserialize<'de> for RecipeCuisine {
@@ -374,8 +424,6 @@ impl<'de> Deserialize<'de> for RecipeCuisine {
     where
         D: Deserializer<'de>,
     {
-        use crate::schema::recipe::RecipeCuisine::Text;
-
         struct Visitor;
 
         impl<'de> de::Visitor<'de> for Visitor {
@@ -389,14 +437,7 @@ impl<'de> Deserialize<'de> for RecipeCuisine {
             where
                 E: de::Error,
             {
-                Ok(Text(v.to_owned()))
-            }
-
-            fn visit_string<E>(self, v: String) -> Result<Self::Value, E>
-            where
-                E: de::Error,
-            {
-                Ok(Text(v))
+                Ok(RecipeCuisine::Texts(split_terms([v])))
             }
 
             fn visit_seq<A>(self, mut seq: A) -> Result<Self::Value, A::Error>
@@ -407,15 +448,35 @@ impl<'de> Deserialize<'de> for RecipeCuisine {
                 while let Some(item) = seq.next_element::<String>()? {
                     vec.push(item);
                 }
-
-                let v = vec
-                    .first()
-                    .ok_or_else(|| de::Error::custom("sequence is empty"))?;
-
-                Ok(Text(v.to_string()))
+                Ok(RecipeCuisine::Texts(split_terms(
+                    vec.iter().map(String::as_str),
+                )))
             }
         }
 
         deserializer.deserialize_any(Visitor)
     }
 }
+
+/// Deserializes the cuisines of a recipe. A cuisine without any term, e.g. "" or " , ", is
+/// `None`.
+fn deserialize_cuisine<'de, D>(deserializer: D) -> Result<Option<RecipeCuisine>, D::Error>
+where
+    D: Deserializer<'de>,
+{
+    Ok(Option::<RecipeCuisine>::deserialize(deserializer)?
+        .filter(|cuisine| !cuisine.values().is_empty()))
+}
+
+/// Splits the comma-separated terms, trims them and removes the duplicates while keeping
+/// the order in which they first appear.
+fn split_terms<'a>(values: impl IntoIterator<Item = &'a str>) -> Vec<String> {
+    let mut terms: Vec<String> = Vec::new();
+    for term in values.into_iter().flat_map(|v| v.split(',')) {
+        let term = term.split_whitespace().collect::<Vec<_>>().join(" ");
+        if !term.is_empty() && !terms.iter().any(|t| t.eq_ignore_ascii_case(&term)) {
+            terms.push(term);
+        }
+    }
+    terms
+}
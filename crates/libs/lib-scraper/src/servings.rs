@@ -0,0 +1,136 @@
+//! Parsing of recipe yields such as "Makes 24 cookies", "4-6 servings" or "1 loaf (12 slices)".
+
+use regex::Regex;
+use std::sync::OnceLock;
+
+use crate::schema::{common::QuantitativeValueOrText, recipe::RecipeSchema};
+
+/// The yield of a recipe broken down into its parts.
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct RecipeYield {
+    /// The number of servings, or portions, e.g. `4` in "4 servings". The lower bound of a
+    /// range.
+    pub servings: Option<u16>,
+
+    /// The bounds of the yield when it is a range, e.g. `(4, 6)` in "4-6 servings".
+    pub range: Option<(u16, u16)>,
+
+    /// What the recipe yields, e.g. "cookies" in "Makes 24 cookies".
+    pub unit_label: Option<String>,
+}
+
+impl RecipeSchema {
+    /// Parses [`RecipeSchema::recipe_yield`].
+    pub fn parsed_yield(&self) -> RecipeYield {
+        match &self.recipe_yield {
+            QuantitativeValueOrText::QuantitativeValue(v) => RecipeYield {
+                servings: u16::try_from(v.value).ok().filter(|&n| n > 0),
+                ..Default::default()
+            },
+            QuantitativeValueOrText::Text(text) => parse_yield(text),
+        }
+    }
+}
+
+/// Parses a yield.
+///
+/// The count in parentheses is preferred because it is the finer one, e.g. 12 slices rather
+/// than 1 loaf in "1 loaf (12 slices)". Dozens are counted as 12 units. Decimal yields, e.g.
+/// "1.5 liters", have no number of servings.
+pub fn parse_yield(s: &str) -> RecipeYield {
+    static INSTANCE: OnceLock<Regex> = OnceLock::new();
+
+    let s = s.trim();
+    if let Some(inner) = parenthesized(s) {
+        let parsed = parse_yield(inner);
+        if parsed.servings.is_some() {
+            return parsed;
+        }
+    }
+
+    let re = INSTANCE.get_or_init(|| {
+        Regex::new(
+            r"(?i)^(.*?)(\d+(?:[.,]\d+)?)(?:\s*(?:-|–|—|to|or|à|a)\s*(\d+(?:[.,]\d+)?))?\s*(.*)$",
+        )
+        .unwrap()
+    });
+
+    let lower = s.to_lowercase();
+    let (prefix, min, max, rest) = match re.captures(s) {
+        Some(caps) => (
+            caps[1].to_lowercase(),
+            parse_count(&caps[2]),
+            caps.get(3).and_then(|m| parse_count(m.as_str())),
+            caps[4].to_string(),
+        ),
+        None => match number_word(&lower) {
+            Some((n, rest)) => (lower.clone(), Some(n), None, rest.to_string()),
+            None => return RecipeYield::default(),
+        },
+    };
+
+    let rest = rest.split('(').next().unwrap_or_default().trim_start();
+    let (factor, rest) = if rest.to_lowercase().starts_with("dozen") {
+        (12, &rest["dozen".len()..])
+    } else {
+        (1, rest)
+    };
+    let min = min.map(|n| n.saturating_mul(factor));
+    let max = max.map(|n| n.saturating_mul(factor));
+
+    let unit_label = Some(
+        rest.trim()
+            .trim_start_matches(|c: char| !c.is_alphanumeric())
+            .trim_end_matches(|c: char| !c.is_alphanumeric())
+            .to_lowercase(),
+    )
+    .filter(|label| !label.is_empty())
+    .or_else(|| {
+        ["serve", "serving", "portion"]
+            .iter()
+            .any(|word| prefix.contains(word))
+            .then(|| "servings".to_string())
+    });
+
+    let servings = min.and_then(|n| u16::try_from(n).ok()).filter(|&n| n > 0);
+    let range = match (servings, max.and_then(|n| u16::try_from(n).ok())) {
+        (Some(min), Some(max)) if max > min => Some((min, max)),
+        _ => None,
+    };
+
+    RecipeYield {
+        servings,
+        range,
+        unit_label,
+    }
+}
+
+/// Parses a whole count, e.g. "4" or "4.0". Decimals such as "4.5" are no count of servings.
+fn parse_count(s: &str) -> Option<u32> {
+    let n: f64 = s.replace(',', ".").parse().ok()?;
+    (n.fract() == 0.0 && n <= f64::from(u32::MAX)).then_some(n as u32)
+}
+
+/// The text between the first pair of parentheses.
+fn parenthesized(s: &str) -> Option<&str> {
+    let start = s.find('(')?;
+    let end = start + s[start..].find(')')?;
+    Some(&s[start + 1..end])
+}
+
+/// Reads a number spelled out at the start of the text, e.g. "one loaf".
+fn number_word(s: &str) -> Option<(u32, &str)> {
+    const WORDS: [&str; 12] = [
+        "one", "two", "three", "four", "five", "six", "seven", "eight", "nine", "ten", "eleven",
+        "twelve",
+    ];
+
+    let s = ["makes", "serves", "yields", "yield:"]
+        .iter()
+        .find_map(|prefix| s.strip_prefix(prefix))
+        .unwrap_or(s)
+        .trim_start();
+    let (word, rest) = s.split_once(char::is_whitespace).unwrap_or((s, ""));
+    let n = WORDS.iter().position(|&w| w == word)? as u32 + 1;
+    Some((n, rest))
+}
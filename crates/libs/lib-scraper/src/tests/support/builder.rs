@@ -0,0 +1,79 @@
+use std::{collections::HashMap, sync::Arc};
+
+use crate::{
+    heuristic::DEFAULT_MIN_CONFIDENCE, politeness::Politeness, websites::Website, HttpClient,
+    ParseMode, Registry, Scraper, SiteExtractor,
+};
+
+use super::StaticHttpClient;
+
+/// Builds a [`Scraper`] around a mock client. The settings are those of the default
+/// [`ScraperConfig`](crate::ScraperConfig), except that the requests are neither paced nor
+/// checked against `robots.txt`.
+pub struct ScraperBuilder {
+    scraper: Scraper,
+}
+
+impl ScraperBuilder {
+    pub fn new(client: Arc<dyn HttpClient + Sync + Send>) -> Self {
+        Self {
+            scraper: Scraper {
+                client,
+                extractors: HashMap::new(),
+                mode: ParseMode::Lenient,
+                politeness: Politeness::disabled(),
+                unknown_websites: false,
+                meta_enrichment: true,
+                text_normalization: true,
+                min_confidence: DEFAULT_MIN_CONFIDENCE,
+                registry: Registry::default(),
+            },
+        }
+    }
+
+    /// A scraper whose every request is answered with the page.
+    pub fn page(html: impl Into<String>) -> Self {
+        Self::new(Arc::new(StaticHttpClient::new(html)))
+    }
+
+    pub fn extractors(mut self, extractors: HashMap<Website, Arc<dyn SiteExtractor>>) -> Self {
+        self.scraper.extractors = extractors;
+        self
+    }
+
+    pub fn mode(mut self, mode: ParseMode) -> Self {
+        self.scraper.mode = mode;
+        self
+    }
+
+    pub fn politeness(mut self, politeness: Politeness) -> Self {
+        self.scraper.politeness = politeness;
+        self
+    }
+
+    pub fn unknown_websites(mut self, allow: bool) -> Self {
+        self.scraper.unknown_websites = allow;
+        self
+    }
+
+    pub fn meta_enrichment(mut self, enable: bool) -> Self {
+        self.scraper.meta_enrichment = enable;
+        self
+    }
+
+    pub fn text_normalization(mut self, enable: bool) -> Self {
+        self.scraper.text_normalization = enable;
+        self
+    }
+
+    /// Sets the registry and the extractors of its websites.
+    pub fn registry(mut self, registry: Registry) -> Self {
+        self.scraper.extractors = registry.extractors().collect();
+        self.scraper.registry = registry;
+        self
+    }
+
+    pub fn build(self) -> Scraper {
+        self.scraper
+    }
+}
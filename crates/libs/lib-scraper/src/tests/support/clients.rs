@@ -0,0 +1,21 @@
+use crate::{websites::Website, HttpClient};
+
+/// Answers every request with the same page.
+pub struct StaticHttpClient(pub String);
+
+impl StaticHttpClient {
+    pub fn new(page: impl Into<String>) -> Self {
+        Self(page.into())
+    }
+}
+
+#[async_trait::async_trait]
+impl HttpClient for StaticHttpClient {
+    async fn get_async<'a>(&'a self, host: Website, url: &str) -> crate::Result<String> {
+        self.get(host, url)
+    }
+
+    fn get(&self, _host: Website, _url: &str) -> crate::Result<String> {
+        Ok(self.0.clone())
+    }
+}
@@ -1,6 +1,10 @@
+mod builder;
+mod clients;
 mod scraper;
 mod websites;
 
+pub use builder::*;
+pub use clients::*;
 pub use scraper::*;
 
 pub type Result<T> = core::result::Result<T, Box<dyn std::error::Error>>;
@@ -5,9 +5,11 @@ use std::{
     sync::{Arc, OnceLock},
 };
 
-use crate::{schema::recipe::RecipeSchema, websites::Website, HttpClient, Scraper};
+use crate::{
+    custom, schema::recipe::RecipeSchema, websites::Website, HttpClient, ParseMode, Scraper,
+};
 
-use super::{websites::websites_for_tests, Result};
+use super::{websites::websites_for_tests, Result, ScraperBuilder};
 
 pub struct MockHttpClient;
 
@@ -33,12 +35,45 @@ impl HttpClient for MockHttpClient {
 fn mock_scraper() -> &'static Scraper {
     static INSTANCE: OnceLock<Scraper> = OnceLock::new();
 
-    INSTANCE.get_or_init(|| Scraper {
-        client: Arc::new(MockHttpClient),
+    INSTANCE.get_or_init(|| {
+        mock_scraper_builder()
+            // The expected recipes are those of the structured data alone.
+            .text_normalization(false)
+            .build()
     })
 }
 
+fn normalizing_mock_scraper() -> &'static Scraper {
+    static INSTANCE: OnceLock<Scraper> = OnceLock::new();
+
+    INSTANCE.get_or_init(|| mock_scraper_builder().build())
+}
+
+fn mock_scraper_builder() -> ScraperBuilder {
+    ScraperBuilder::new(Arc::new(MockHttpClient))
+        .extractors(custom::builtin_extractors())
+        .mode(ParseMode::Strict)
+        .meta_enrichment(false)
+}
+
+/// Scrapes the page of the website as its structured data gives it.
 pub fn scrape(website: Website, number: usize) -> Result<RecipeSchema> {
+    scrape_with(mock_scraper(), false, website, number)
+}
+
+/// Scrapes the page of the website with its text normalized, like the default scraper does.
+pub fn scrape_normalized(website: Website, number: usize) -> Result<RecipeSchema> {
+    scrape_with(normalizing_mock_scraper(), true, website, number)
+}
+
+/// Scrapes the page in strict mode, and checks that the lenient mode the scraper defaults to
+/// reads the clean structured data the same way, and that the default scraper reads the page.
+fn scrape_with(
+    scraper: &Scraper,
+    text_normalization: bool,
+    website: Website,
+    number: usize,
+) -> Result<RecipeSchema> {
     let url = match websites_for_tests().get(&website) {
         Some(urls) => urls.get(number).expect("url to test not in vector of urls"),
         None => panic!("website '{}' not found in map", website),
@@ -66,5 +101,25 @@ pub fn scrape(website: Website, number: usize) -> Result<RecipeSchema> {
         }
     }
 
-    Ok(mock_scraper().scrape(url)?)
+    let (recipe, report) = scraper.scrape_with_report(url)?;
+
+    if report.is_clean() {
+        let lenient = mock_scraper_builder()
+            .mode(ParseMode::Lenient)
+            .text_normalization(text_normalization)
+            .build()
+            .scrape(url)?;
+        pretty_assertions::assert_eq!(lenient, recipe, "{website} in lenient mode");
+    }
+
+    let default = ScraperBuilder::new(Arc::new(MockHttpClient))
+        .extractors(custom::builtin_extractors())
+        .build()
+        .scrape(url);
+    assert!(
+        default.is_ok(),
+        "{website} with the default settings: {default:?}"
+    );
+
+    Ok(recipe)
 }
@@ -0,0 +1,154 @@
+#[cfg(test)]
+mod tests {
+    use std::{
+        path::PathBuf,
+        sync::{Arc, Mutex},
+        time::Duration,
+    };
+
+    use crate::{
+        cache::{CachedHttpClient, Fetched, Validators},
+        websites::Website,
+        CacheConfig, Error, HttpClient,
+    };
+
+    type Result<T> = core::result::Result<T, Box<dyn std::error::Error>>;
+
+    /// Answers 304 when the request carries the current ETag, and records the validators
+    /// of every request.
+    #[derive(Clone, Default)]
+    struct EtagHttpClient {
+        requests: Arc<Mutex<Vec<Validators>>>,
+    }
+
+    #[async_trait::async_trait]
+    impl HttpClient for EtagHttpClient {
+        async fn get_async<'a>(&'a self, host: Website, url: &str) -> crate::Result<String> {
+            self.get(host, url)
+        }
+
+        fn get(&self, host: Website, url: &str) -> crate::Result<String> {
+            match self.get_conditional(host, url, &Validators::default())? {
+                Fetched::Body { body, .. } => Ok(body),
+                Fetched::NotModified => unreachable!(),
+            }
+        }
+
+        async fn get_conditional_async<'a>(
+            &'a self,
+            host: Website,
+            url: &str,
+            validators: &Validators,
+        ) -> crate::Result<Fetched> {
+            self.get_conditional(host, url, validators)
+        }
+
+        fn get_conditional(
+            &self,
+            _host: Website,
+            url: &str,
+            validators: &Validators,
+        ) -> crate::Result<Fetched> {
+            self.requests.lock().unwrap().push(validators.clone());
+            if validators.etag.as_deref() == Some("\"v1\"") {
+                return Ok(Fetched::NotModified);
+            }
+            Ok(Fetched::Body {
+                body: format!("<html>{url}</html>"),
+                validators: Validators {
+                    etag: Some("\"v1\"".to_string()),
+                    last_modified: None,
+                },
+            })
+        }
+    }
+
+    fn fx_dir(name: &str) -> PathBuf {
+        let dir =
+            std::env::temp_dir().join(format!("lib-scraper-cache-{name}-{}", std::process::id()));
+        let _ = std::fs::remove_dir_all(&dir);
+        dir
+    }
+
+    const FX_URL: &str = "https://www.allrecipes.com/recipe/1";
+
+    #[test]
+    fn test_fresh_page_served_from_cache_ok() -> Result<()> {
+        let fx_inner = EtagHttpClient::default();
+        let client = CachedHttpClient::new(fx_inner.clone(), CacheConfig::new(fx_dir("fresh")))?;
+
+        let first = client.get(Website::AllRecipesDotCom, FX_URL)?;
+        let second = client.get(Website::AllRecipesDotCom, FX_URL)?;
+
+        assert_eq!(first, second);
+        assert_eq!(fx_inner.requests.lock().unwrap().len(), 1);
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn test_stale_page_revalidated_ok() -> Result<()> {
+        let fx_inner = EtagHttpClient::default();
+        let fx_config = CacheConfig::new(fx_dir("stale")).ttl(Duration::ZERO);
+        let client = CachedHttpClient::new(fx_inner.clone(), fx_config)?;
+
+        let first = client.get_async(Website::AllRecipesDotCom, FX_URL).await?;
+        let second = client.get_async(Website::AllRecipesDotCom, FX_URL).await?;
+
+        assert_eq!(first, second);
+        let requests = fx_inner.requests.lock().unwrap();
+        assert_eq!(requests.len(), 2);
+        assert_eq!(requests[0], Validators::default());
+        assert_eq!(requests[1].etag.as_deref(), Some("\"v1\""));
+        Ok(())
+    }
+
+    #[test]
+    fn test_offline_replay_ok() -> Result<()> {
+        let fx_dir = fx_dir("offline");
+        let fx_inner = EtagHttpClient::default();
+        CachedHttpClient::new(fx_inner.clone(), CacheConfig::new(&fx_dir))?
+            .get(Website::AllRecipesDotCom, FX_URL)?;
+
+        let client = CachedHttpClient::new(
+            fx_inner.clone(),
+            CacheConfig::new(&fx_dir).ttl(Duration::ZERO).offline(true),
+        )?;
+
+        assert_eq!(
+            client.get(Website::AllRecipesDotCom, FX_URL)?,
+            format!("<html>{FX_URL}</html>")
+        );
+        let got = client.get(
+            Website::AllRecipesDotCom,
+            "https://www.allrecipes.com/recipe/2",
+        );
+        assert!(matches!(got, Err(Error::CacheMiss { url }) if url.ends_with("/recipe/2")));
+        assert_eq!(fx_inner.requests.lock().unwrap().len(), 1);
+        Ok(())
+    }
+
+    #[test]
+    fn test_size_cap_evicts_oldest_ok() -> Result<()> {
+        let fx_dir = fx_dir("evict");
+        let fx_inner = EtagHttpClient::default();
+        let client =
+            CachedHttpClient::new(fx_inner.clone(), CacheConfig::new(&fx_dir).max_size(400))?;
+
+        for i in 0..5 {
+            client.get(Website::AllRecipesDotCom, &format!("{FX_URL}{i}"))?;
+            std::thread::sleep(Duration::from_millis(10));
+        }
+
+        let size: u64 = std::fs::read_dir(&fx_dir)?
+            .map(|entry| entry.map(|e| e.metadata().map(|m| m.len()).unwrap_or_default()))
+            .sum::<std::io::Result<u64>>()?;
+        assert!(size <= 400, "size: {size}");
+
+        // The newest page is still cached while the oldest was evicted.
+        client.get(Website::AllRecipesDotCom, &format!("{FX_URL}4"))?;
+        assert_eq!(fx_inner.requests.lock().unwrap().len(), 5);
+        client.get(Website::AllRecipesDotCom, &format!("{FX_URL}0"))?;
+        assert_eq!(fx_inner.requests.lock().unwrap().len(), 6);
+        Ok(())
+    }
+}
@@ -0,0 +1,210 @@
+#[cfg(test)]
+mod tests {
+    use std::{collections::HashMap, sync::Arc};
+
+    use crate::{
+        tests::support::ScraperBuilder, websites::Website, CrawlConfig, Error, HttpClient, Scraper,
+    };
+
+    type Result<T> = core::result::Result<T, Box<dyn std::error::Error>>;
+
+    struct MapHttpClient {
+        pages: HashMap<String, String>,
+    }
+
+    #[async_trait::async_trait]
+    impl HttpClient for MapHttpClient {
+        async fn get_async<'a>(&'a self, host: Website, url: &str) -> crate::Result<String> {
+            self.get(host, url)
+        }
+
+        fn get(&self, _host: Website, url: &str) -> crate::Result<String> {
+            self.pages.get(url).cloned().ok_or(Error::HttpStatus {
+                status: 404,
+                url: url.to_string(),
+            })
+        }
+    }
+
+    fn fx_scraper(pages: &[(&str, &str)]) -> Scraper {
+        let pages = pages
+            .iter()
+            .map(|(url, content)| (url.to_string(), content.to_string()))
+            .collect();
+
+        ScraperBuilder::new(Arc::new(MapHttpClient { pages }))
+            .unknown_websites(true)
+            .build()
+    }
+
+    fn fx_site() -> Scraper {
+        fx_scraper(&[
+            (
+                "https://soupes.example/robots.txt",
+                "User-agent: *\nDisallow: /wp-admin/\nSitemap: https://soupes.example/sitemap_index.xml\n",
+            ),
+            (
+                "https://soupes.example/sitemap_index.xml",
+                r#"<?xml version="1.0" encoding="UTF-8"?>
+                <sitemapindex xmlns="http://www.sitemaps.org/schemas/sitemap/0.9">
+                    <sitemap><loc>https://soupes.example/post-sitemap.xml</loc></sitemap>
+                    <sitemap><loc>https://soupes.example/page-sitemap.xml</loc></sitemap>
+                    <sitemap><loc>https://soupes.example/category-sitemap.xml</loc></sitemap>
+                </sitemapindex>"#,
+            ),
+            (
+                "https://soupes.example/post-sitemap.xml",
+                r#"<?xml version="1.0" encoding="UTF-8"?>
+                <urlset xmlns="http://www.sitemaps.org/schemas/sitemap/0.9">
+                    <url><loc>https://soupes.example/</loc></url>
+                    <url><loc>https://soupes.example/pea-soup/</loc></url>
+                    <url><loc><![CDATA[https://soupes.example/carrot-soup/]]></loc></url>
+                    <url><loc>https://soupes.example/onion-soup/?lang=en&amp;units=metric</loc></url>
+                    <url><loc>https://soupes.example/wp-content/uploads/soup.jpg</loc></url>
+                </urlset>"#,
+            ),
+            (
+                "https://soupes.example/page-sitemap.xml",
+                r#"<urlset><url><loc>https://soupes.example/about/</loc></url></urlset>"#,
+            ),
+            (
+                "https://soupes.example/feed",
+                r#"<?xml version="1.0" encoding="UTF-8"?>
+                <rss version="2.0"><channel>
+                    <title>Soupes</title>
+                    <link>https://soupes.example</link>
+                    <item><title>Leek soup</title><link>https://soupes.example/leek-soup/</link></item>
+                    <item><title>Pea soup</title><link>https://soupes.example/pea-soup/</link></item>
+                </channel></rss>"#,
+            ),
+        ])
+    }
+
+    #[tokio::test]
+    async fn test_discover_sitemaps_and_feed_ok() -> Result<()> {
+        let fx_scraper = fx_site();
+
+        let got = fx_scraper
+            .discover("https://soupes.example", &CrawlConfig::default())
+            .await?;
+
+        pretty_assertions::assert_eq!(
+            got,
+            vec![
+                "https://soupes.example/leek-soup/",
+                "https://soupes.example/pea-soup/",
+                "https://soupes.example/carrot-soup/",
+                "https://soupes.example/onion-soup/?lang=en&units=metric",
+            ]
+        );
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn test_discover_known_urls_ok() -> Result<()> {
+        let fx_scraper = fx_site();
+        let fx_config = CrawlConfig::default()
+            .known_urls([
+                "http://www.soupes.example/pea-soup",
+                "https://soupes.example/leek-soup/",
+            ])
+            .max_urls(1);
+
+        let got = fx_scraper
+            .discover("https://soupes.example/", &fx_config)
+            .await?;
+
+        pretty_assertions::assert_eq!(got, vec!["https://soupes.example/carrot-soup/"]);
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn test_discover_category_pages_depth_ok() -> Result<()> {
+        let fx_scraper = fx_scraper(&[
+            (
+                "https://soupes.example/category/soups/",
+                r#"<html><body>
+                    <a href="/pea-soup/#comments">Pea soup</a>
+                    <a href="https://other.example/stew/">Stew</a>
+                    <a href="/category/soups/page/2/">Next</a>
+                </body></html>"#,
+            ),
+            (
+                "https://soupes.example/category/soups/page/2/",
+                r#"<html><body>
+                    <a href="/carrot-soup/">Carrot soup</a>
+                    <a href="/category/soups/page/3/">Next</a>
+                </body></html>"#,
+            ),
+            (
+                "https://soupes.example/category/soups/page/3/",
+                r#"<html><body><a href="/leek-soup/">Leek soup</a></body></html>"#,
+            ),
+        ]);
+
+        let got = fx_scraper
+            .discover(
+                "https://soupes.example/category/soups/",
+                &CrawlConfig::default().max_depth(1),
+            )
+            .await?;
+
+        pretty_assertions::assert_eq!(
+            got,
+            vec![
+                "https://soupes.example/pea-soup/",
+                "https://soupes.example/carrot-soup/",
+            ]
+        );
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn test_discover_category_page_navigation_ok() -> Result<()> {
+        let fx_scraper = fx_scraper(&[(
+            "https://soupes.example/category/soups/",
+            r#"<html><body>
+                <nav>
+                    <a href="/about-us/">About</a>
+                    <a href="/contact-me/">Contact</a>
+                    <a href="/privacy-policy/">Privacy</a>
+                    <a href="/shop-my-kitchen/">Shop</a>
+                    <a href="/wp-content/uploads/menu.pdf">Menu</a>
+                </nav>
+                <a href="/pea-soup/">Pea soup</a>
+                <a href="/tagine-soup/">Tagine soup</a>
+            </body></html>"#,
+        )]);
+
+        let got = fx_scraper
+            .discover(
+                "https://soupes.example/category/soups/",
+                &CrawlConfig::default(),
+            )
+            .await?;
+
+        pretty_assertions::assert_eq!(
+            got,
+            vec![
+                "https://soupes.example/pea-soup/",
+                "https://soupes.example/tagine-soup/",
+            ]
+        );
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn test_discover_unreachable_err() -> Result<()> {
+        let fx_scraper = fx_scraper(&[]);
+
+        let got = fx_scraper
+            .discover("https://soupes.example", &CrawlConfig::default())
+            .await;
+
+        assert!(
+            matches!(got, Err(Error::HttpStatus { status: 404, .. })),
+            "{got:?}"
+        );
+        Ok(())
+    }
+}
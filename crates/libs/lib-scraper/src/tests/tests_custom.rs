@@ -0,0 +1,103 @@
+#[cfg(test)]
+mod tests {
+    use std::{collections::HashMap, sync::Arc};
+
+    use crate::{
+        schema::{common::*, recipe::RecipeSchema, AtType},
+        tests::support::ScraperBuilder,
+        websites::Website,
+        Error, ParseMode, Scraper, SelectorExtractor, SiteExtractor,
+    };
+
+    type Result<T> = core::result::Result<T, Box<dyn std::error::Error>>;
+
+    fn fx_scraper(html: &'static str, extractor: Option<SelectorExtractor>) -> Scraper {
+        let mut extractors: HashMap<Website, Arc<dyn SiteExtractor>> = HashMap::new();
+        if let Some(extractor) = extractor {
+            extractors.insert(Website::AllRecipesDotCom, Arc::new(extractor));
+        }
+
+        ScraperBuilder::page(html)
+            .extractors(extractors)
+            .mode(ParseMode::Strict)
+            .build()
+    }
+
+    #[test]
+    fn test_selector_extractor_patches_generic_ok() -> Result<()> {
+        let fx_html = r#"
+            <script type="application/ld+json">
+                {"@context": "https://schema.org", "@type": "Recipe", "name": "Cookies", "recipeIngredient": ["wrong"]}
+            </script>
+            <ul class="ingredients"><li> 2 cups  flour </li><li>1 cup sugar</li></ul>
+            <img class="hero" src="/img/cookies.jpg">
+        "#;
+        let fx_extractor = SelectorExtractor {
+            ingredients: Some("ul.ingredients > li".to_string()),
+            image: Some("img.hero".to_string()),
+            ..Default::default()
+        };
+
+        let got = fx_scraper(fx_html, Some(fx_extractor))
+            .scrape("https://www.allrecipes.com/recipe/10813/best-chocolate-chip-cookies")?;
+
+        let want = RecipeSchema {
+            at_type: Some(AtType::Recipe),
+            name: Some("Cookies".to_string()),
+            image: Some(ImageObjectOrUrl::Url(url::Url::parse(
+                "https://www.allrecipes.com/img/cookies.jpg",
+            )?)),
+            recipe_ingredient: Some(vec!["2 cups flour".to_string(), "1 cup sugar".to_string()]),
+            ..Default::default()
+        };
+        pretty_assertions::assert_eq!(got, want);
+        Ok(())
+    }
+
+    #[test]
+    fn test_selector_extractor_without_structured_data_ok() -> Result<()> {
+        let fx_html = r#"
+            <h1 class="title">Cookies</h1>
+            <ol class="steps"><li>Mix.</li><li>Bake.</li></ol>
+        "#;
+        let fx_extractor = SelectorExtractor {
+            name: Some("h1.title".to_string()),
+            instructions: Some("ol.steps > li".to_string()),
+            ..Default::default()
+        };
+
+        let got = fx_scraper(fx_html, Some(fx_extractor))
+            .scrape("https://www.allrecipes.com/recipe/10813/best-chocolate-chip-cookies")?;
+
+        let want = RecipeSchema {
+            at_type: Some(AtType::Recipe),
+            name: Some("Cookies".to_string()),
+            recipe_instructions: Some(CreativeWorkOrItemListOrText::ItemList(vec![
+                HowTo {
+                    at_type: AtType::HowToStep,
+                    text: "Mix.".to_string(),
+                    ..Default::default()
+                },
+                HowTo {
+                    at_type: AtType::HowToStep,
+                    text: "Bake.".to_string(),
+                    ..Default::default()
+                },
+            ])),
+            ..Default::default()
+        };
+        pretty_assertions::assert_eq!(got, want);
+        Ok(())
+    }
+
+    #[test]
+    fn test_no_extractor_without_structured_data_err() -> Result<()> {
+        let fx_html = r#"<h1 class="title">Cookies</h1>"#;
+
+        let got = fx_scraper(fx_html, None)
+            .scrape("https://www.allrecipes.com/recipe/10813/best-chocolate-chip-cookies");
+
+        assert!(matches!(got, Err(Error::NoStructuredData)));
+        Ok(())
+    }
+}
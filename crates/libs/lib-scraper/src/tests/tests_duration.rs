@@ -0,0 +1,94 @@
+#[cfg(test)]
+mod tests {
+    use crate::{
+        duration::{parse_duration, to_seconds},
+        schema::recipe::RecipeSchema,
+    };
+
+    type Result<T> = core::result::Result<T, Box<dyn std::error::Error>>;
+
+    #[test]
+    fn test_parse_duration_ok() {
+        let fx_cases = [
+            ("PT1H15M", Some(4500)),
+            ("P0DT1H", Some(3600)),
+            ("pt20m", Some(1200)),
+            ("PT1.5H", Some(5400)),
+            ("30 minutes", Some(1800)),
+            ("1 hr 15 mins", Some(4500)),
+            ("1h30", Some(5400)),
+            ("1:30", Some(5400)),
+            ("1.5 hours", Some(5400)),
+            ("45", Some(2700)),
+            ("20-30 min", Some(1800)),
+            ("2 heures 10 minutes", Some(7800)),
+            ("1 Stunde 20 Minuten", Some(4800)),
+            ("1 hora y 10 minutos", Some(4200)),
+            ("1 1/2 hours", Some(5400)),
+            ("1½ hours", Some(5400)),
+            ("½ hour", Some(1800)),
+            ("Prep: 10-15 min", Some(900)),
+            ("PT-5M", None),
+            ("P-1D", None),
+            ("99999999999 days", None),
+            ("PT", None),
+            ("", None),
+            ("a while", None),
+            ("4 servings", None),
+        ];
+
+        for (fx, want) in fx_cases {
+            assert_eq!(parse_duration(fx).map(to_seconds), want, "parsing {fx:?}");
+        }
+    }
+
+    #[test]
+    fn test_recipe_lenient_times_ok() -> Result<()> {
+        let got: RecipeSchema = serde_json::from_str(
+            r#"{
+                "@type": "Recipe",
+                "prepTime": "15 mins",
+                "cookTime": 40,
+                "performTime": null,
+                "totalTime": "not long at all"
+            }"#,
+        )?;
+
+        assert_eq!(got.prep_seconds(), Some(900));
+        assert_eq!(got.cook_seconds(), Some(2400));
+        assert_eq!(got.perform_time, None);
+        assert_eq!(got.total_time, None);
+        assert_eq!(got.total_seconds(), Some(3300));
+        Ok(())
+    }
+
+    #[test]
+    fn test_recipe_times_of_other_types_ok() -> Result<()> {
+        let got: RecipeSchema = serde_json::from_str(
+            r#"{
+                "@type": "Recipe",
+                "prepTime": ["PT20M", "PT25M"],
+                "cookTime": {"@type": "Duration", "name": "PT1H"},
+                "totalTime": false
+            }"#,
+        )?;
+
+        assert_eq!(got.prep_seconds(), Some(1200));
+        assert_eq!(got.cook_time, None);
+        assert_eq!(got.total_time, None);
+        assert_eq!(got.total_seconds(), Some(1200));
+        Ok(())
+    }
+
+    #[test]
+    fn test_recipe_total_seconds_ok() -> Result<()> {
+        let got: RecipeSchema = serde_json::from_str(
+            r#"{"@type": "Recipe", "prepTime": "PT10M", "totalTime": "PT1H"}"#,
+        )?;
+        assert_eq!(got.total_seconds(), Some(3600));
+
+        let got: RecipeSchema = serde_json::from_str(r#"{"@type": "Recipe"}"#)?;
+        assert_eq!(got.total_seconds(), None);
+        Ok(())
+    }
+}
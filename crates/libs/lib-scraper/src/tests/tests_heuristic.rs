@@ -0,0 +1,166 @@
+#[cfg(test)]
+mod tests {
+    use url::Url;
+
+    use crate::{schema::common::ImageObjectOrUrl, Error, ScrapeReport, Scraper, ScraperConfig};
+
+    type Result<T> = core::result::Result<T, Box<dyn std::error::Error>>;
+
+    const FX_URL: &str = "https://grandmas-kitchen.example/2009/03/pea-soup.html";
+
+    fn fx_parse(html: &str) -> crate::Result<(crate::schema::recipe::RecipeSchema, ScrapeReport)> {
+        fx_parse_with(ScraperConfig::default(), html)
+    }
+
+    fn fx_parse_with(
+        config: ScraperConfig,
+        html: &str,
+    ) -> crate::Result<(crate::schema::recipe::RecipeSchema, ScrapeReport)> {
+        let scraper: Scraper = config.build()?;
+        scraper.parse_html_with_report(html, Some(&Url::parse(FX_URL).unwrap()))
+    }
+
+    #[test]
+    fn test_heuristic_lists_ok() -> Result<()> {
+        let fx_html = r#"<html><head>
+            <meta property="og:title" content="Grandma's pea soup">
+            <meta property="og:image" content="/images/pea-soup.jpg">
+        </head><body>
+            <ul class="menu"><li>Home</li><li>About</li><li>Archive</li></ul>
+            <h1>Pea soup</h1>
+            <ul>
+                <li>500 g dried split peas</li>
+                <li>2 onions, chopped</li>
+                <li>1½ l water</li>
+                <li>salt and pepper</li>
+            </ul>
+            <ol>
+                <li>Rinse the peas and soak them overnight.</li>
+                <li>Simmer the peas with the onions for an hour.</li>
+            </ol>
+        </body></html>"#;
+
+        let (got, got_report) = fx_parse(fx_html)?;
+
+        assert_eq!(got.name, Some("Grandma's pea soup".to_string()));
+        assert_eq!(
+            got.image,
+            Some(ImageObjectOrUrl::Url(Url::parse(
+                "https://grandmas-kitchen.example/images/pea-soup.jpg"
+            )?))
+        );
+        assert_eq!(got.recipe_ingredient.as_ref().map(Vec::len), Some(4));
+        let got_steps: Vec<String> = got
+            .instruction_sections()
+            .into_iter()
+            .flat_map(|section| section.steps)
+            .map(|step| step.text)
+            .collect();
+        assert_eq!(
+            got_steps,
+            vec![
+                "Rinse the peas and soak them overnight.".to_string(),
+                "Simmer the peas with the onions for an hour.".to_string()
+            ]
+        );
+        let confidence = got_report.confidence.unwrap_or_default();
+        assert!(confidence > 0.8, "{confidence}");
+        Ok(())
+    }
+
+    #[test]
+    fn test_heuristic_numbered_paragraphs_ok() -> Result<()> {
+        let fx_html = r#"<h1>Pancakes</h1>
+            <ul><li>2 eggs</li><li>250 ml milk</li><li>125 g flour</li></ul>
+            <p>Mix everything.</p>
+            <p>1. Whisk the eggs with the milk.</p>
+            <p>Step 2: Add the flour and rest the batter.</p>"#;
+
+        let (got, got_report) = fx_parse(fx_html)?;
+
+        assert_eq!(got.name, Some("Pancakes".to_string()));
+        assert_eq!(got.instruction_sections()[0].steps.len(), 2);
+        let confidence = got_report.confidence.unwrap_or_default();
+        assert!((0.5..1.0).contains(&confidence), "{confidence}");
+        Ok(())
+    }
+
+    #[test]
+    fn test_heuristic_other_ld_json_ok() -> Result<()> {
+        let fx_html = r#"<html><head>
+            <script type="application/ld+json">{"@context": "https://schema.org", "@graph": [
+                {"@type": "WebSite", "name": "Grandma's kitchen", "url": "https://grandmas-kitchen.example/"},
+                {"@type": "BreadcrumbList", "itemListElement": [
+                    {"@type": "ListItem", "position": 1, "name": "Home", "item": "https://grandmas-kitchen.example/"}
+                ]}
+            ]}</script>
+        </head><body>
+            <h1>Pea soup</h1>
+            <ul><li>500 g dried split peas</li><li>2 onions, chopped</li><li>1½ l water</li></ul>
+            <ol>
+                <li>Rinse the peas and soak them overnight.</li>
+                <li>Simmer the peas with the onions for an hour.</li>
+            </ol>
+        </body></html>"#;
+
+        let (got, got_report) = fx_parse(fx_html)?;
+
+        assert_eq!(got.name, Some("Pea soup".to_string()));
+        assert_eq!(got.recipe_ingredient.as_ref().map(Vec::len), Some(3));
+        assert!(got_report.confidence.is_some());
+        Ok(())
+    }
+
+    #[test]
+    fn test_heuristic_no_ingredients_err() -> Result<()> {
+        let fx_html = r#"<h1>My holidays</h1>
+            <ul><li>Paris</li><li>Rome</li><li>Lisbon</li></ul>
+            <ol><li>We flew to Paris on a rainy morning.</li><li>Then we took the train to Rome.</li></ol>"#;
+
+        assert!(matches!(fx_parse(fx_html), Err(Error::NoStructuredData)));
+        Ok(())
+    }
+
+    #[test]
+    fn test_heuristic_archive_list_err() -> Result<()> {
+        let fx_html = r#"<html><head>
+            <meta property="og:title" content="Grandma's kitchen">
+            <meta property="og:image" content="/images/kitchen.jpg">
+        </head><body>
+            <h1>Archives</h1>
+            <ul><li>2019 (12)</li><li>2018 (30)</li><li>2017 (25)</li></ul>
+        </body></html>"#;
+
+        let got = fx_parse_with(ScraperConfig::default().min_confidence(0.0), fx_html);
+
+        assert!(matches!(got, Err(Error::NoStructuredData)), "{got:?}");
+        Ok(())
+    }
+
+    #[test]
+    fn test_heuristic_min_confidence_ok() -> Result<()> {
+        let fx_html = r#"<h1>Pea soup</h1>
+            <ul><li>500 g dried split peas</li><li>2 onions</li><li>1 l water</li></ul>"#;
+
+        let got = fx_parse(fx_html);
+        assert!(matches!(got, Err(Error::NoStructuredData)), "{got:?}");
+
+        let (got, got_report) =
+            fx_parse_with(ScraperConfig::default().min_confidence(0.5), fx_html)?;
+        assert_eq!(got.recipe_ingredient.as_ref().map(Vec::len), Some(3));
+        assert_eq!(got_report.confidence, Some(0.6));
+        Ok(())
+    }
+
+    #[test]
+    fn test_structured_data_has_no_confidence_ok() -> Result<()> {
+        let fx_html = r#"<script type="application/ld+json">{"@type": "Recipe", "name": "Pea soup"}</script>
+            <ul><li>500 g dried split peas</li><li>2 onions</li></ul>"#;
+
+        let (got, got_report) = fx_parse(fx_html)?;
+
+        assert_eq!(got.recipe_ingredient, None);
+        assert_eq!(got_report.confidence, None);
+        Ok(())
+    }
+}
@@ -0,0 +1,128 @@
+#[cfg(test)]
+mod tests {
+    use std::{
+        sync::{Arc, Mutex},
+        time::Duration,
+    };
+    use tokio::{
+        io::{AsyncReadExt, AsyncWriteExt},
+        net::TcpListener,
+    };
+
+    use crate::{http::AppHttpClient, websites::Website, Error, HttpClient, ScraperConfig};
+
+    type Result<T> = core::result::Result<T, Box<dyn std::error::Error>>;
+
+    const FX_OK: &str = "HTTP/1.1 200 OK\r\nConnection: close\r\nContent-Length: 5\r\n\r\nhello";
+    const FX_UNAVAILABLE: &str =
+        "HTTP/1.1 503 Service Unavailable\r\nConnection: close\r\nRetry-After: 0\r\nContent-Length: 0\r\n\r\n";
+    const FX_TOO_MANY: &str =
+        "HTTP/1.1 429 Too Many Requests\r\nConnection: close\r\nContent-Length: 0\r\n\r\n";
+    const FX_TOO_MANY_RETRY_LATER: &str =
+        "HTTP/1.1 429 Too Many Requests\r\nConnection: close\r\nRetry-After: 120\r\nContent-Length: 0\r\n\r\n";
+
+    /// Serves the responses in order, one per connection, and records the requests.
+    async fn fx_server(responses: Vec<&'static str>) -> Result<(String, Arc<Mutex<Vec<String>>>)> {
+        let listener = TcpListener::bind("127.0.0.1:0").await?;
+        let url = format!("http://{}/recipe", listener.local_addr()?);
+        let requests = Arc::new(Mutex::new(Vec::new()));
+
+        let requests_clone = requests.clone();
+        tokio::spawn(async move {
+            for response in responses {
+                let Ok((mut stream, _)) = listener.accept().await else {
+                    return;
+                };
+
+                let mut buf = vec![0; 4096];
+                let n = stream.read(&mut buf).await.unwrap_or_default();
+                requests_clone
+                    .lock()
+                    .unwrap()
+                    .push(String::from_utf8_lossy(&buf[..n]).to_lowercase());
+
+                let _ = stream.write_all(response.as_bytes()).await;
+                let _ = stream.shutdown().await;
+            }
+        });
+
+        Ok((url, requests))
+    }
+
+    fn fx_config() -> ScraperConfig {
+        ScraperConfig::default()
+            .user_agent("Recipya tests")
+            .backoff(Duration::from_millis(1), Duration::from_millis(10))
+    }
+
+    #[tokio::test]
+    async fn test_retry_on_unavailable_ok() -> Result<()> {
+        let (fx_url, requests) = fx_server(vec![FX_UNAVAILABLE, FX_TOO_MANY, FX_OK]).await?;
+        let client = AppHttpClient::new(fx_config())?;
+
+        let got = client.get_async(Website::AllRecipesDotCom, &fx_url).await?;
+
+        assert_eq!(got, "hello");
+        let requests = requests.lock().unwrap();
+        assert_eq!(requests.len(), 3);
+        assert!(requests[0].contains("user-agent: recipya tests"));
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn test_retries_exhausted_err() -> Result<()> {
+        let (fx_url, _) = fx_server(vec![FX_UNAVAILABLE, FX_UNAVAILABLE]).await?;
+        let client = AppHttpClient::new(fx_config().max_retries(1))?;
+
+        let got = client.get_async(Website::AllRecipesDotCom, &fx_url).await;
+
+        assert!(matches!(got, Err(Error::HttpStatus { status: 503, .. })));
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn test_retry_after_beyond_max_backoff_err() -> Result<()> {
+        let (fx_url, requests) = fx_server(vec![FX_TOO_MANY_RETRY_LATER, FX_OK]).await?;
+        let client = AppHttpClient::new(fx_config())?;
+
+        let got = client.get_async(Website::AllRecipesDotCom, &fx_url).await;
+
+        assert!(matches!(got, Err(Error::HttpStatus { status: 429, .. })));
+        assert_eq!(requests.lock().unwrap().len(), 1);
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn test_max_body_size_err() -> Result<()> {
+        let (fx_url, _) = fx_server(vec![FX_OK]).await?;
+        let client = AppHttpClient::new(fx_config().max_body_size(3))?;
+
+        let got = client.get_async(Website::AllRecipesDotCom, &fx_url).await;
+
+        assert!(matches!(got, Err(Error::BodyTooLarge { limit: 3 })));
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn test_blocking_retry_ok() -> Result<()> {
+        let (fx_url, requests) = fx_server(vec![FX_TOO_MANY, FX_OK]).await?;
+
+        let got = tokio::task::spawn_blocking(move || {
+            let client = AppHttpClient::new(fx_config())?;
+            client.get(Website::AllRecipesDotCom, &fx_url)
+        })
+        .await??;
+
+        assert_eq!(got, "hello");
+        assert_eq!(requests.lock().unwrap().len(), 2);
+        Ok(())
+    }
+
+    #[test]
+    fn test_invalid_proxy_err() -> Result<()> {
+        let got = fx_config().proxy("not a proxy").build();
+
+        assert!(got.is_err());
+        Ok(())
+    }
+}
@@ -0,0 +1,135 @@
+#[cfg(test)]
+mod tests {
+    use url::Url;
+
+    use crate::{
+        images::RankedImages,
+        schema::{common::ImageObjectOrUrl, recipe::RecipeSchema},
+        ScraperConfig,
+    };
+
+    type Result<T> = core::result::Result<T, Box<dyn std::error::Error>>;
+
+    fn urls(urls: &[&str]) -> Result<Vec<Url>> {
+        Ok(urls
+            .iter()
+            .map(|url| Url::parse(url))
+            .collect::<core::result::Result<_, _>>()?)
+    }
+
+    #[test]
+    fn test_image_array_deserialize_ok() -> Result<()> {
+        let got: RecipeSchema = serde_json::from_str(
+            r#"{"@type": "Recipe", "image": [
+                "https://soupes.example/soup-1x1.jpg",
+                {"@type": "ImageObject", "url": "https://soupes.example/soup.jpg"}
+            ]}"#,
+        )?;
+
+        assert!(matches!(got.image, Some(ImageObjectOrUrl::Images(images)) if images.len() == 2));
+        Ok(())
+    }
+
+    #[test]
+    fn test_ranked_images_ok() -> Result<()> {
+        let got: RecipeSchema = serde_json::from_str(
+            r#"{
+                "@type": "Recipe",
+                "image": [
+                    "https://soupes.example/soup.jpg",
+                    "https://soupes.example/soup-500x500.jpg",
+                    "https://soupes.example/soup-500x375.jpg",
+                    "https://soupes.example/soup-480x270.jpg",
+                    {"@type": "ImageObject", "url": "https://soupes.example/tall.jpg", "width": 900, "height": 1600},
+                    {"@type": "ImageObject", "url": "https://soupes.example/wide.jpg", "width": "1200 px", "height": "675 px"}
+                ],
+                "thumbnailUrl": "https://cdn.example/soup.jpg?w=150&h=150",
+                "thumbnail": {"@type": "ImageObject", "contentUrl": "https://soupes.example/soup-500x500.jpg"}
+            }"#,
+        )?;
+
+        let want = RankedImages {
+            best: Url::parse("https://soupes.example/wide.jpg")?,
+            alternates: urls(&[
+                "https://soupes.example/tall.jpg",
+                "https://soupes.example/soup.jpg",
+                "https://soupes.example/soup-500x500.jpg",
+                "https://soupes.example/soup-500x375.jpg",
+                "https://soupes.example/soup-480x270.jpg",
+                "https://cdn.example/soup.jpg?w=150&h=150",
+            ])?,
+        };
+        pretty_assertions::assert_eq!(got.ranked_images(), Some(want));
+        Ok(())
+    }
+
+    #[test]
+    fn test_ranked_images_thumbnails_last_ok() -> Result<()> {
+        let got: RecipeSchema = serde_json::from_str(
+            r#"{
+                "@type": "Recipe",
+                "image": "https://soupes.example/soup.jpg",
+                "thumbnailUrl": "https://soupes.example/soup-150x150.jpg"
+            }"#,
+        )?;
+
+        let want = RankedImages {
+            best: Url::parse("https://soupes.example/soup.jpg")?,
+            alternates: urls(&["https://soupes.example/soup-150x150.jpg"])?,
+        };
+        pretty_assertions::assert_eq!(got.ranked_images(), Some(want));
+        Ok(())
+    }
+
+    #[test]
+    fn test_image_array_invalid_entry_ok() -> Result<()> {
+        let got: RecipeSchema = serde_json::from_str(
+            r#"{"@type": "Recipe", "image": ["not an image", "https://soupes.example/soup.jpg"]}"#,
+        )?;
+
+        assert_eq!(
+            got.image,
+            Some(ImageObjectOrUrl::Url(Url::parse(
+                "https://soupes.example/soup.jpg"
+            )?))
+        );
+        Ok(())
+    }
+
+    #[test]
+    fn test_relative_image_urls_ok() -> Result<()> {
+        let fx_html = r#"<script type="application/ld+json">{
+            "@context": "https://schema.org",
+            "@type": "Recipe",
+            "name": "Pea soup",
+            "image": [
+                "/uploads/soup-500x500.jpg",
+                {"@type": "ImageObject", "url": "../uploads/soup.jpg"},
+                "https://cdn.example/soup.jpg"
+            ],
+            "thumbnailUrl": "/uploads/soup-150x150.jpg"
+        }</script>"#;
+        let fx_url = Url::parse("https://soupes.example/recipes/pea-soup")?;
+
+        let got = ScraperConfig::default()
+            .unknown_websites(true)
+            .build()?
+            .parse_html(fx_html, Some(&fx_url))?;
+
+        let want = RankedImages {
+            best: Url::parse("https://soupes.example/uploads/soup.jpg")?,
+            alternates: urls(&[
+                "https://soupes.example/uploads/soup-500x500.jpg",
+                "https://cdn.example/soup.jpg",
+                "https://soupes.example/uploads/soup-150x150.jpg",
+            ])?,
+        };
+        pretty_assertions::assert_eq!(got.ranked_images(), Some(want));
+        Ok(())
+    }
+
+    #[test]
+    fn test_ranked_images_none_ok() {
+        assert_eq!(RecipeSchema::default().ranked_images(), None);
+    }
+}
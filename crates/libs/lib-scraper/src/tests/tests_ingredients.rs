@@ -0,0 +1,122 @@
+#[cfg(test)]
+mod tests {
+    use crate::ingredients::*;
+
+    type Result<T> = core::result::Result<T, Box<dyn std::error::Error>>;
+
+    fn fx_ingredient(
+        quantity: Option<Quantity>,
+        unit: Option<Unit>,
+        name: &str,
+        preparation: Option<&str>,
+    ) -> ParsedIngredient {
+        ParsedIngredient {
+            quantity,
+            unit,
+            name: name.to_string(),
+            preparation: preparation.map(String::from),
+            alternatives: Vec::new(),
+        }
+    }
+
+    #[test]
+    fn test_parse_ingredient_ok() -> Result<()> {
+        use Quantity::*;
+
+        let fx_cases = [
+            (
+                "1 1/2 cups all-purpose flour, sifted",
+                fx_ingredient(
+                    Some(Amount(1.5)),
+                    Some(Unit::Cup),
+                    "all-purpose flour",
+                    Some("sifted"),
+                ),
+            ),
+            (
+                "1½ tsp baking soda",
+                fx_ingredient(Some(Amount(1.5)), Some(Unit::Teaspoon), "baking soda", None),
+            ),
+            (
+                "¾ cup of sugar",
+                fx_ingredient(Some(Amount(0.75)), Some(Unit::Cup), "sugar", None),
+            ),
+            (
+                "3 or 4 ripe bananas, smashed",
+                fx_ingredient(Some(Range(3.0, 4.0)), None, "ripe bananas", Some("smashed")),
+            ),
+            (
+                "2-3 T olive oil",
+                fx_ingredient(
+                    Some(Range(2.0, 3.0)),
+                    Some(Unit::Tablespoon),
+                    "olive oil",
+                    None,
+                ),
+            ),
+            (
+                "12.5g butter",
+                fx_ingredient(Some(Amount(12.5)), Some(Unit::Gram), "butter", None),
+            ),
+            (
+                "1 (14 oz) can diced tomatoes, drained",
+                fx_ingredient(
+                    Some(Amount(1.0)),
+                    Some(Unit::Can),
+                    "diced tomatoes",
+                    Some("drained, 14 oz"),
+                ),
+            ),
+            (
+                "8 fl oz heavy cream",
+                fx_ingredient(
+                    Some(Amount(8.0)),
+                    Some(Unit::FluidOunce),
+                    "heavy cream",
+                    None,
+                ),
+            ),
+            (
+                "Salt (optional)",
+                fx_ingredient(None, None, "Salt", Some("optional")),
+            ),
+            (
+                "2 eggs",
+                fx_ingredient(Some(Amount(2.0)), None, "eggs", None),
+            ),
+            (
+                "1,000 g flour",
+                fx_ingredient(Some(Amount(1000.0)), Some(Unit::Gram), "flour", None),
+            ),
+            (
+                "1,5 kg potatoes",
+                fx_ingredient(Some(Amount(1.5)), Some(Unit::Kilogram), "potatoes", None),
+            ),
+        ];
+
+        for (line, want) in fx_cases {
+            pretty_assertions::assert_eq!(parse_ingredient(line), want, "line: {line}");
+        }
+        Ok(())
+    }
+
+    #[test]
+    fn test_parse_ingredient_alternatives_ok() -> Result<()> {
+        let got = parse_ingredient("1 cup butter or 3/4 cup vegetable oil, melted");
+
+        let want = ParsedIngredient {
+            quantity: Some(Quantity::Amount(1.0)),
+            unit: Some(Unit::Cup),
+            name: "butter".to_string(),
+            preparation: Some("melted".to_string()),
+            alternatives: vec![ParsedIngredient {
+                quantity: Some(Quantity::Amount(0.75)),
+                unit: Some(Unit::Cup),
+                name: "vegetable oil".to_string(),
+                ..Default::default()
+            }],
+        };
+        pretty_assertions::assert_eq!(got, want);
+        Ok(())
+    }
+}
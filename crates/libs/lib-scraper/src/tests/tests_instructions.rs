@@ -0,0 +1,226 @@
+#[cfg(test)]
+mod tests {
+    use crate::{
+        instructions::{InstructionSection, InstructionStep},
+        RecipeSchema,
+    };
+    use url::Url;
+
+    type Result<T> = core::result::Result<T, Box<dyn std::error::Error>>;
+
+    fn fx_step(text: &str) -> InstructionStep {
+        InstructionStep {
+            text: text.to_string(),
+            ..Default::default()
+        }
+    }
+
+    #[test]
+    fn test_instruction_sections_ok() -> Result<()> {
+        let fx_json = r#"{
+            "@type": "Recipe",
+            "recipeInstructions": [
+                {"@type": "HowToStep", "text": "Preheat the oven to 350&nbsp;F."},
+                {
+                    "@type": "HowToSection",
+                    "name": "For the sauce",
+                    "itemListElement": [
+                        {"@type": "HowToStep", "text": "<p>Melt the <b>butter</b>.</p>"},
+                        {
+                            "@type": "HowToStep",
+                            "text": "Whisk in the flour.",
+                            "url": "https://www.example.com/pasta#step-3",
+                            "image": "https://www.example.com/whisk.jpg"
+                        }
+                    ]
+                },
+                {
+                    "@type": "HowToSection",
+                    "name": "For the pasta",
+                    "itemListElement": [{"@type": "HowToStep", "text": "Boil the pasta."}]
+                }
+            ]
+        }"#;
+        let fx_recipe: RecipeSchema = serde_json::from_str(fx_json)?;
+
+        let got = fx_recipe.instruction_sections();
+
+        let want = vec![
+            InstructionSection {
+                name: None,
+                steps: vec![fx_step("Preheat the oven to 350 F.")],
+            },
+            InstructionSection {
+                name: Some("For the sauce".to_string()),
+                steps: vec![
+                    fx_step("Melt the butter."),
+                    InstructionStep {
+                        text: "Whisk in the flour.".to_string(),
+                        image: Some(Url::parse("https://www.example.com/whisk.jpg")?),
+                        url: Some(Url::parse("https://www.example.com/pasta#step-3")?),
+                    },
+                ],
+            },
+            InstructionSection {
+                name: Some("For the pasta".to_string()),
+                steps: vec![fx_step("Boil the pasta.")],
+            },
+        ];
+        pretty_assertions::assert_eq!(got, want);
+        Ok(())
+    }
+
+    #[test]
+    fn test_instruction_sections_text_ok() -> Result<()> {
+        let fx_json = r#"{
+            "@type": "Recipe",
+            "recipeInstructions": "<p>Mix the flour &amp; eggs.</p><p>Knead.<br/>Rest for 1 hour.</p>"
+        }"#;
+        let fx_recipe: RecipeSchema = serde_json::from_str(fx_json)?;
+
+        let got = fx_recipe.instruction_sections();
+
+        let want = vec![InstructionSection {
+            name: None,
+            steps: vec![
+                fx_step("Mix the flour & eggs."),
+                fx_step("Knead."),
+                fx_step("Rest for 1 hour."),
+            ],
+        }];
+        pretty_assertions::assert_eq!(got, want);
+        Ok(())
+    }
+
+    #[test]
+    fn test_instruction_sections_string_list_ok() -> Result<()> {
+        let fx_json = r#"{"@type": "Recipe", "recipeInstructions": ["Mix.", " ", "Bake."]}"#;
+        let fx_recipe: RecipeSchema = serde_json::from_str(fx_json)?;
+
+        let got = fx_recipe.instruction_sections();
+
+        let want = vec![InstructionSection {
+            name: None,
+            steps: vec![fx_step("Mix."), fx_step("Bake.")],
+        }];
+        pretty_assertions::assert_eq!(got, want);
+        Ok(())
+    }
+
+    #[test]
+    fn test_instruction_sections_item_list_ok() -> Result<()> {
+        let fx_json = r#"{
+            "@type": "Recipe",
+            "recipeInstructions": {
+                "@type": "ItemList",
+                "itemListElement": [
+                    {"@type": "HowToStep", "text": "Preheat the oven."},
+                    {
+                        "@type": "HowToSection",
+                        "name": "For the pie",
+                        "itemListElement": [
+                            {"@type": "HowToStep", "text": "Fill the crust."},
+                            {
+                                "@type": "HowToSection",
+                                "name": "For the crust",
+                                "itemListElement": [
+                                    {"@type": "ListItem", "position": 1, "item": {"@type": "HowToStep", "text": "Roll the dough."}}
+                                ]
+                            }
+                        ]
+                    }
+                ]
+            }
+        }"#;
+        let fx_recipe: RecipeSchema = serde_json::from_str(fx_json)?;
+
+        let got = fx_recipe.instruction_sections();
+
+        let want = vec![
+            InstructionSection {
+                name: None,
+                steps: vec![fx_step("Preheat the oven.")],
+            },
+            InstructionSection {
+                name: Some("For the pie".to_string()),
+                steps: vec![fx_step("Fill the crust.")],
+            },
+            InstructionSection {
+                name: Some("For the crust".to_string()),
+                steps: vec![fx_step("Roll the dough.")],
+            },
+        ];
+        pretty_assertions::assert_eq!(got, want);
+        Ok(())
+    }
+
+    #[test]
+    fn test_instruction_sections_item_list_of_steps_ok() -> Result<()> {
+        let fx_json = r#"{
+            "@type": "Recipe",
+            "recipeInstructions": {
+                "@type": "ItemList",
+                "itemListElement": [
+                    {"@type": "HowToStep", "text": "Mix."},
+                    {"@type": "HowToStep", "text": "Bake."}
+                ]
+            }
+        }"#;
+        let fx_recipe: RecipeSchema = serde_json::from_str(fx_json)?;
+
+        let got = fx_recipe.instruction_sections();
+
+        let want = vec![InstructionSection {
+            name: None,
+            steps: vec![fx_step("Mix."), fx_step("Bake.")],
+        }];
+        pretty_assertions::assert_eq!(got, want);
+        Ok(())
+    }
+
+    #[test]
+    fn test_instruction_sections_nested_order_ok() -> Result<()> {
+        let fx_json = r#"{
+            "@type": "Recipe",
+            "recipeInstructions": [
+                {
+                    "@type": "HowToSection",
+                    "@id": "https://soupes.example/pie#section-1",
+                    "position": 1,
+                    "name": "For the pie",
+                    "itemListElement": [
+                        {"@type": "HowToStep", "text": "Make the dough."},
+                        {
+                            "@type": "HowToSection",
+                            "position": 2,
+                            "url": "https://soupes.example/pie#filling",
+                            "name": "For the filling",
+                            "itemListElement": [{"@type": "HowToStep", "text": "Cook the apples."}]
+                        },
+                        {"@type": "HowToStep", "text": "Bake."}
+                    ]
+                }
+            ]
+        }"#;
+        let fx_recipe: RecipeSchema = serde_json::from_str(fx_json)?;
+
+        let got = fx_recipe.instruction_sections();
+
+        let want = vec![
+            InstructionSection {
+                name: Some("For the pie".to_string()),
+                steps: vec![fx_step("Make the dough.")],
+            },
+            InstructionSection {
+                name: Some("For the filling".to_string()),
+                steps: vec![fx_step("Cook the apples.")],
+            },
+            InstructionSection {
+                name: None,
+                steps: vec![fx_step("Bake.")],
+            },
+        ];
+        pretty_assertions::assert_eq!(got, want);
+        Ok(())
+    }
+}
@@ -0,0 +1,101 @@
+#[cfg(test)]
+mod tests {
+    use url::Url;
+
+    use crate::{
+        schema::common::{ImageObjectOrUrl, LanguageOrText, TextOrTextObject},
+        FieldSource, ScraperConfig,
+    };
+
+    type Result<T> = core::result::Result<T, Box<dyn std::error::Error>>;
+
+    const FX_HTML: &str = r#"<html lang="fr-CA"><head>
+        <meta name="twitter:image" content="https://soupes.example/img/twitter.jpg">
+        <meta property="og:description" content="Une soupe épaisse.">
+        <link rel="canonical" href="/soupe-aux-pois">
+        <script type="application/ld+json">{"@type": "Recipe", "name": "Soupe aux pois"}</script>
+    </head></html>"#;
+
+    #[test]
+    fn test_meta_enrichment_ok() -> Result<()> {
+        let fx_url = Url::parse("https://soupes.example/soupe-aux-pois?utm_source=feed")?;
+
+        let (got, got_report) = ScraperConfig::default()
+            .build()?
+            .parse_html_with_report(FX_HTML, Some(&fx_url))?;
+
+        assert_eq!(
+            got.image,
+            Some(ImageObjectOrUrl::Url(Url::parse(
+                "https://soupes.example/img/twitter.jpg"
+            )?))
+        );
+        assert_eq!(
+            got.description,
+            Some(TextOrTextObject::Text("Une soupe épaisse.".to_string()))
+        );
+        assert_eq!(
+            got.url,
+            Some(Url::parse("https://soupes.example/soupe-aux-pois")?)
+        );
+        assert_eq!(
+            got.in_language,
+            Some(LanguageOrText::Text("fr-CA".to_string()))
+        );
+        let got_sources: Vec<(&str, &FieldSource)> = got_report
+            .filled_fields
+            .iter()
+            .map(|(field, source)| (field.as_str(), source))
+            .collect();
+        assert_eq!(
+            got_sources,
+            vec![
+                (
+                    "description",
+                    &FieldSource::Meta("og:description".to_string())
+                ),
+                ("image", &FieldSource::Meta("twitter:image".to_string())),
+                ("inLanguage", &FieldSource::HtmlLang),
+                ("url", &FieldSource::CanonicalLink),
+            ]
+        );
+        Ok(())
+    }
+
+    #[test]
+    fn test_meta_enrichment_keeps_structured_data_ok() -> Result<()> {
+        let fx_html = r#"<html lang="en"><head>
+            <meta property="og:image" content="https://soups.example/og.jpg">
+            <script type="application/ld+json">
+                {"@type": "Recipe", "name": "Pea soup", "image": "https://soups.example/pea.jpg"}
+            </script>
+        </head></html>"#;
+
+        let (got, got_report) = ScraperConfig::default()
+            .build()?
+            .parse_html_with_report(fx_html, None)?;
+
+        assert_eq!(
+            got.image,
+            Some(ImageObjectOrUrl::Url(Url::parse(
+                "https://soups.example/pea.jpg"
+            )?))
+        );
+        assert!(!got_report.filled_fields.contains_key("image"));
+        assert!(got_report.filled_fields.contains_key("inLanguage"));
+        Ok(())
+    }
+
+    #[test]
+    fn test_meta_enrichment_disabled_ok() -> Result<()> {
+        let (got, got_report) = ScraperConfig::default()
+            .meta_enrichment(false)
+            .build()?
+            .parse_html_with_report(FX_HTML, None)?;
+
+        assert_eq!(got.image, None);
+        assert_eq!(got.in_language, None);
+        assert!(got_report.filled_fields.is_empty());
+        Ok(())
+    }
+}
@@ -0,0 +1,165 @@
+#[cfg(test)]
+mod tests {
+    use crate::{microdata, schema::*, RecipeSchema};
+    use common::*;
+    use iso8601::Duration::YMDHMS;
+    use nutrition::*;
+    use recipe::*;
+    use scraper::Html;
+    use url::Url;
+
+    type Result<T> = core::result::Result<T, Box<dyn std::error::Error>>;
+
+    #[test]
+    fn test_microdata_ok() -> Result<()> {
+        let fx_html = r#"
+            <html><body>
+            <div itemscope itemtype="https://schema.org/Recipe">
+                <h1 itemprop="name">Mom's World Famous Banana Bread</h1>
+                <span itemprop="author" itemscope itemtype="https://schema.org/Person">
+                    <span itemprop="name">John Smith</span>
+                </span>
+                <img itemprop="image" src="/images/bananabread.jpg" alt="Banana bread on a plate" />
+                <span itemprop="description">This classic banana bread recipe comes from my mom.</span>
+                <meta itemprop="prepTime" content="PT15M">Prep Time: 15 minutes
+                <meta itemprop="cookTime" content="PT1H">Cook time: 1 hour
+                Yield: <span itemprop="recipeYield">1 loaf</span>
+                <div itemprop="nutrition" itemscope itemtype="https://schema.org/NutritionInformation">
+                    Nutrition facts: <span itemprop="calories">240 calories</span>,
+                    <span itemprop="fatContent">9 grams fat</span>
+                </div>
+                Ingredients:
+                <ul>
+                    <li itemprop="recipeIngredient">3 or 4 ripe bananas, smashed</li>
+                    <li itemprop="recipeIngredient">1 egg</li>
+                    <li itemprop="recipeIngredient">3/4 cup of sugar</li>
+                </ul>
+                <ol itemprop="recipeInstructions">
+                    <li>Preheat the oven to 350 degrees.</li>
+                </ol>
+                <div itemprop="review" itemscope itemtype="https://schema.org/Review">
+                    <span itemprop="name">Not a happy camper</span>
+                </div>
+            </div>
+            </body></html>
+        "#;
+
+        let got = microdata::extract(
+            &Html::parse_document(fx_html),
+            "https://www.example.com/banana-bread",
+        )?;
+
+        let want = RecipeSchema {
+            at_type: Some(AtType::Recipe),
+            author: Some(OrganizationType {
+                at_type: AtType::Person,
+                name: Some("John Smith".to_string()),
+                ..Default::default()
+            }),
+            cook_time: Some(YMDHMS {
+                year: 0,
+                month: 0,
+                day: 0,
+                hour: 1,
+                minute: 0,
+                second: 0,
+                millisecond: 0,
+            }),
+            description: Some(TextOrTextObject::Text(
+                "This classic banana bread recipe comes from my mom.".to_string(),
+            )),
+            image: Some(ImageObjectOrUrl::Url(Url::parse(
+                "https://www.example.com/images/bananabread.jpg",
+            )?)),
+            name: Some("Mom's World Famous Banana Bread".to_string()),
+            nutrition: Some(NutritionInformationSchema {
+                at_type: Some(AtType::NutritionInformation),
+                calories: Some(Energy::Value(240.0, EnergyUnit::Kilocalorie)),
+                fat_content: Some(Mass::Value(9.0, MassUnit::Gram)),
+                ..Default::default()
+            }),
+            prep_time: Some(YMDHMS {
+                year: 0,
+                month: 0,
+                day: 0,
+                hour: 0,
+                minute: 15,
+                second: 0,
+                millisecond: 0,
+            }),
+            recipe_ingredient: Some(vec![
+                "3 or 4 ripe bananas, smashed".to_string(),
+                "1 egg".to_string(),
+                "3/4 cup of sugar".to_string(),
+            ]),
+            recipe_instructions: Some(CreativeWorkOrItemListOrText::ItemList(vec![HowTo {
+                at_type: AtType::HowToStep,
+                text: "Preheat the oven to 350 degrees.".to_string(),
+                ..Default::default()
+            }])),
+            recipe_yield: QuantitativeValueOrText::Text("1 loaf".to_string()),
+            ..Default::default()
+        };
+        pretty_assertions::assert_eq!(got, vec![want]);
+        Ok(())
+    }
+
+    #[test]
+    fn test_rdfa_ok() -> Result<()> {
+        let fx_html = r#"
+            <html><body vocab="https://schema.org/">
+            <div typeof="Recipe">
+                <h1 property="name">Pancakes</h1>
+                <span property="recipeCategory">Breakfast</span>
+                <ul>
+                    <li property="recipeIngredient">2 cups flour</li>
+                    <li property="recipeIngredient">2 eggs</li>
+                </ul>
+                <div property="recipeInstructions" typeof="HowToStep">
+                    <p property="text">Mix everything.</p>
+                </div>
+                <div property="recipeInstructions" typeof="HowToStep">
+                    <p property="text">Cook on a hot griddle.</p>
+                </div>
+            </div>
+            </body></html>
+        "#;
+
+        let got = microdata::extract(
+            &Html::parse_document(fx_html),
+            "https://www.example.com/pancakes",
+        )?;
+
+        let want = RecipeSchema {
+            at_type: Some(AtType::Recipe),
+            name: Some("Pancakes".to_string()),
+            recipe_category: RecipeCategory::Texts(vec!["Breakfast".to_string()]),
+            recipe_ingredient: Some(vec!["2 cups flour".to_string(), "2 eggs".to_string()]),
+            recipe_instructions: Some(CreativeWorkOrItemListOrText::ItemList(vec![
+                HowTo {
+                    at_type: AtType::HowToStep,
+                    text: "Mix everything.".to_string(),
+                    ..Default::default()
+                },
+                HowTo {
+                    at_type: AtType::HowToStep,
+                    text: "Cook on a hot griddle.".to_string(),
+                    ..Default::default()
+                },
+            ])),
+            ..Default::default()
+        };
+        pretty_assertions::assert_eq!(got, vec![want]);
+        Ok(())
+    }
+
+    #[test]
+    fn test_microdata_no_recipe_ok() -> Result<()> {
+        let fx_html = r#"<div itemscope itemtype="https://schema.org/Person"><span itemprop="name">Jane</span></div>"#;
+
+        let got = microdata::extract(&Html::parse_document(fx_html), "https://www.example.com")?;
+
+        assert!(got.is_empty());
+        Ok(())
+    }
+}
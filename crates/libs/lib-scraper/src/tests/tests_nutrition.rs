@@ -0,0 +1,77 @@
+#[cfg(test)]
+mod tests {
+    use crate::schema::nutrition::*;
+
+    type Result<T> = core::result::Result<T, Box<dyn std::error::Error>>;
+
+    #[test]
+    fn test_energy_parse_ok() -> Result<()> {
+        let fx_cases = [
+            ("250 kcal", Energy::Value(250.0, EnergyUnit::Kilocalorie)),
+            ("1046 kJ", Energy::Value(1046.0, EnergyUnit::Kilojoule)),
+            ("1,046 kJ", Energy::Value(1046.0, EnergyUnit::Kilojoule)),
+            (
+                "240 calories",
+                Energy::Value(240.0, EnergyUnit::Kilocalorie),
+            ),
+            ("312", Energy::Value(312.0, EnergyUnit::Kilocalorie)),
+            ("lots", Energy::Str("lots".to_string())),
+            ("12 parsecs", Energy::Str("12 parsecs".to_string())),
+        ];
+
+        for (fx_value, want) in fx_cases {
+            pretty_assertions::assert_eq!(Energy::parse(fx_value), want, "value: {fx_value}");
+        }
+        Ok(())
+    }
+
+    #[test]
+    fn test_mass_parse_ok() -> Result<()> {
+        let fx_cases = [
+            ("12.5g", Mass::Value(12.5, MassUnit::Gram)),
+            ("3,6 mg", Mass::Value(3.6, MassUnit::Milligram)),
+            ("40 µg", Mass::Value(40.0, MassUnit::Microgram)),
+            ("9 grams fat", Mass::Value(9.0, MassUnit::Gram)),
+            ("< 1 g", Mass::Value(1.0, MassUnit::Gram)),
+            ("n/a", Mass::Str("n/a".to_string())),
+        ];
+
+        for (fx_value, want) in fx_cases {
+            pretty_assertions::assert_eq!(Mass::parse(fx_value), want, "value: {fx_value}");
+        }
+        Ok(())
+    }
+
+    #[test]
+    fn test_conversions_ok() -> Result<()> {
+        assert_eq!(Energy::parse("1046 kJ").kcal().map(to_smallint), Some(250));
+        assert_eq!(
+            Energy::parse("250 kcal").kilojoules().map(to_smallint),
+            Some(1046)
+        );
+        assert_eq!(Mass::parse("271.1 mg").grams(), Some(0.2711));
+        assert_eq!(
+            Mass::parse("1.2 g").milligrams().map(to_smallint),
+            Some(1200)
+        );
+        assert_eq!(Mass::parse("unknown").grams(), None);
+        assert_eq!(to_smallint(1e9), i16::MAX);
+        Ok(())
+    }
+
+    #[test]
+    fn test_nutrition_deserialize_numbers_ok() -> Result<()> {
+        let fx_json = r#"{"calories": 320, "fatContent": 12.5, "sodiumContent": "410 mg"}"#;
+
+        let got: NutritionInformationSchema = serde_json::from_str(fx_json)?;
+
+        let want = NutritionInformationSchema {
+            calories: Some(Energy::Value(320.0, EnergyUnit::Kilocalorie)),
+            fat_content: Some(Mass::Value(12.5, MassUnit::Gram)),
+            sodium_content: Some(Mass::Value(410.0, MassUnit::Milligram)),
+            ..Default::default()
+        };
+        pretty_assertions::assert_eq!(got, want);
+        Ok(())
+    }
+}
@@ -0,0 +1,79 @@
+#[cfg(test)]
+mod tests {
+    use url::Url;
+
+    use crate::{parse_html, schema::common::ImageObjectOrUrl, Error, Registry, ScraperConfig};
+
+    type Result<T> = core::result::Result<T, Box<dyn std::error::Error>>;
+
+    #[test]
+    fn test_parse_html_without_url_ok() -> Result<()> {
+        let fx_html = r#"<html><head><script type="application/ld+json">
+            {"@context": "https://schema.org", "@type": "Recipe", "name": "Pea soup"}
+        </script></head></html>"#;
+
+        let got = parse_html(fx_html, None)?;
+
+        assert_eq!(got.name, Some("Pea soup".to_string()));
+        Ok(())
+    }
+
+    #[test]
+    fn test_parse_html_without_type_ok() -> Result<()> {
+        let fx_html = r#"<script type="application/ld+json">
+            {"name": "No type soup", "recipeIngredient": ["1 egg"]}
+        </script>"#;
+
+        let got = parse_html(fx_html, None)?;
+
+        assert_eq!(got.name, Some("No type soup".to_string()));
+        assert_eq!(got.recipe_ingredient, Some(vec!["1 egg".to_string()]));
+        Ok(())
+    }
+
+    #[test]
+    fn test_parse_html_resolves_relative_links_ok() -> Result<()> {
+        let fx_html = r#"<div itemscope itemtype="https://schema.org/Recipe">
+            <h1 itemprop="name">Pea soup</h1>
+            <img itemprop="image" src="/img/pea-soup.jpg">
+        </div>"#;
+        let fx_url = Url::parse("https://www.unknown-recipes.example/recipes/pea-soup")?;
+
+        let (got, got_report) = ScraperConfig::default()
+            .build()?
+            .parse_html_with_report(fx_html, Some(&fx_url))?;
+
+        assert_eq!(
+            got.image,
+            Some(ImageObjectOrUrl::Url(Url::parse(
+                "https://www.unknown-recipes.example/img/pea-soup.jpg"
+            )?))
+        );
+        assert!(got_report.unverified_site);
+        Ok(())
+    }
+
+    #[test]
+    fn test_parse_html_website_hints_ok() -> Result<()> {
+        let fx_registry = Registry::from_toml(
+            r#"
+            [[websites]]
+            host = "family-recipes.example"
+
+            [websites.selectors]
+            name = "h1.title"
+            "#,
+        )?;
+        let scraper = ScraperConfig::default().registry(fx_registry).build()?;
+        let fx_html = r#"<h1 class="title">Pea soup</h1>"#;
+        let fx_url = Url::parse("https://family-recipes.example/pea-soup")?;
+
+        let (got, got_report) = scraper.parse_html_with_report(fx_html, Some(&fx_url))?;
+        assert_eq!(got.name, Some("Pea soup".to_string()));
+        assert!(!got_report.unverified_site);
+
+        let got = scraper.parse_html(fx_html, None);
+        assert!(matches!(got, Err(Error::NoStructuredData)));
+        Ok(())
+    }
+}
@@ -0,0 +1,195 @@
+#[cfg(test)]
+mod tests {
+    use std::{
+        sync::{
+            atomic::{AtomicUsize, Ordering},
+            Arc,
+        },
+        time::{Duration, Instant},
+    };
+
+    use crate::{
+        politeness::Politeness, robots::Robots, tests::support::ScraperBuilder, websites::Website,
+        Error, HttpClient, ParseMode, Scraper, ScraperConfig,
+    };
+
+    type Result<T> = core::result::Result<T, Box<dyn std::error::Error>>;
+
+    const FX_ROBOTS: &str = "
+        # Comments are ignored
+        User-agent: Googlebot
+        Disallow: /
+
+        User-agent: *
+        Disallow: /search
+        Disallow: /*.pdf$
+        Allow: /search/recipes
+        Crawl-delay: 2
+
+        User-agent: recipya
+        Disallow: /private/
+    ";
+
+    struct RobotsHttpClient {
+        robots: &'static str,
+        robots_status: u16,
+        robots_requests: AtomicUsize,
+    }
+
+    #[async_trait::async_trait]
+    impl HttpClient for RobotsHttpClient {
+        async fn get_async<'a>(&'a self, host: Website, url: &str) -> crate::Result<String> {
+            self.get(host, url)
+        }
+
+        fn get(&self, _host: Website, url: &str) -> crate::Result<String> {
+            if url.ends_with("/robots.txt") {
+                self.robots_requests.fetch_add(1, Ordering::SeqCst);
+                if self.robots_status != 200 {
+                    return Err(Error::HttpStatus {
+                        status: self.robots_status,
+                        url: url.to_string(),
+                    });
+                }
+                return Ok(self.robots.to_string());
+            }
+            Ok(r#"<script type="application/ld+json">{"@type": "Recipe", "name": "Stew"}</script>"#.to_string())
+        }
+    }
+
+    fn fx_scraper(config: ScraperConfig) -> (Scraper, Arc<RobotsHttpClient>) {
+        fx_scraper_with_status(config, 200)
+    }
+
+    fn fx_scraper_with_status(
+        config: ScraperConfig,
+        robots_status: u16,
+    ) -> (Scraper, Arc<RobotsHttpClient>) {
+        let client = Arc::new(RobotsHttpClient {
+            robots: FX_ROBOTS,
+            robots_status,
+            robots_requests: AtomicUsize::new(0),
+        });
+        let scraper = ScraperBuilder::new(client.clone())
+            .mode(ParseMode::Strict)
+            .politeness(Politeness::new(&config))
+            .build();
+        (scraper, client)
+    }
+
+    #[test]
+    fn test_robots_wildcard_group_ok() -> Result<()> {
+        let robots = Robots::parse(FX_ROBOTS, "Mozilla/5.0 (X11; Linux x86_64)");
+
+        let fx_cases = [
+            ("/recipe/1", true),
+            ("/search?q=stew", false),
+            ("/search/recipes?q=stew", true),
+            ("/guides/stew.pdf", false),
+            ("/guides/stew.pdf?download=1", true),
+            ("/private/drafts", true),
+        ];
+        for (fx_path, want) in fx_cases {
+            assert_eq!(robots.is_allowed(fx_path), want, "path: {fx_path}");
+        }
+        assert_eq!(robots.crawl_delay(), Some(Duration::from_secs(2)));
+        Ok(())
+    }
+
+    #[test]
+    fn test_robots_specific_group_ok() -> Result<()> {
+        let robots = Robots::parse(FX_ROBOTS, "Recipya/1.0");
+
+        assert!(robots.is_allowed("/search"));
+        assert!(!robots.is_allowed("/private/drafts"));
+        assert_eq!(robots.crawl_delay(), None);
+        Ok(())
+    }
+
+    #[test]
+    fn test_disallowed_by_robots_err() -> Result<()> {
+        let (scraper, client) = fx_scraper(ScraperConfig::default().without_rate_limit());
+
+        let got = scraper.scrape("https://www.allrecipes.com/search?q=stew");
+        assert!(matches!(got, Err(Error::DisallowedByRobots { .. })));
+
+        let got = scraper.scrape("https://www.allrecipes.com/recipe/1")?;
+        assert_eq!(got.name, Some("Stew".to_string()));
+
+        assert_eq!(client.robots_requests.load(Ordering::SeqCst), 1);
+        Ok(())
+    }
+
+    #[test]
+    fn test_robots_missing_ok() -> Result<()> {
+        let (scraper, client) =
+            fx_scraper_with_status(ScraperConfig::default().without_rate_limit(), 404);
+
+        for i in 0..2 {
+            let got = scraper.scrape(&format!("https://www.allrecipes.com/search?q={i}"))?;
+            assert_eq!(got.name, Some("Stew".to_string()));
+        }
+
+        assert_eq!(client.robots_requests.load(Ordering::SeqCst), 1);
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn test_robots_unreachable_err() -> Result<()> {
+        let (scraper, client) =
+            fx_scraper_with_status(ScraperConfig::default().without_rate_limit(), 503);
+
+        let got = scraper.scrape("https://www.allrecipes.com/recipe/1");
+        assert!(matches!(got, Err(Error::DisallowedByRobots { .. })));
+
+        let got = scraper
+            .scrape_async("https://www.allrecipes.com/recipe/1")
+            .await;
+        assert!(matches!(got, Err(Error::DisallowedByRobots { .. })));
+
+        assert_eq!(client.robots_requests.load(Ordering::SeqCst), 2);
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn test_robots_fetched_once_ok() -> Result<()> {
+        let (scraper, client) = fx_scraper(
+            ScraperConfig::default()
+                .without_rate_limit()
+                .user_agent("Recipya"),
+        );
+        let fx_urls: Vec<String> = (0..8)
+            .map(|i| format!("https://www.allrecipes.com/recipe/{i}"))
+            .collect();
+
+        let got = scraper.scrape_many(&fx_urls).await;
+
+        assert!(got.iter().all(|res| res.is_ok()));
+        assert_eq!(client.robots_requests.load(Ordering::SeqCst), 1);
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn test_rate_limit_ok() -> Result<()> {
+        let (scraper, _) = fx_scraper(
+            ScraperConfig::default()
+                .respect_robots_txt(false)
+                .rate_limit(50.0, 2),
+        );
+        let fx_urls: Vec<String> = (0..5)
+            .map(|i| format!("https://www.allrecipes.com/recipe/{i}"))
+            .collect();
+
+        let start = Instant::now();
+        let got = scraper.scrape_many(&fx_urls).await;
+
+        assert!(got.iter().all(|res| res.is_ok()));
+        // Two requests are sent right away and the other three wait 20 ms each.
+        assert!(
+            start.elapsed() >= Duration::from_millis(55),
+            "{:?}",
+            start.elapsed()
+        );
+        Ok(())
+    }
+}
@@ -0,0 +1,113 @@
+#[cfg(test)]
+mod tests {
+    use crate::schema::recipe::*;
+
+    type Result<T> = core::result::Result<T, Box<dyn std::error::Error>>;
+
+    fn fx_terms(terms: &[&str]) -> Vec<String> {
+        terms.iter().map(|t| t.to_string()).collect()
+    }
+
+    #[test]
+    fn test_recipe_category_and_cuisine_ok() -> Result<()> {
+        let fx_json = r#"{
+            "@type": "Recipe",
+            "recipeCategory": "Dinner, Main Course,  Pasta ,dinner",
+            "recipeCuisine": ["Italian", "French, Italian", " "]
+        }"#;
+
+        let got: RecipeSchema = serde_json::from_str(fx_json)?;
+
+        pretty_assertions::assert_eq!(
+            got.recipe_category,
+            RecipeCategory::Texts(fx_terms(&["Dinner", "Main Course", "Pasta"]))
+        );
+        pretty_assertions::assert_eq!(
+            got.recipe_cuisine,
+            Some(RecipeCuisine::Texts(fx_terms(&["Italian", "French"])))
+        );
+        Ok(())
+    }
+
+    #[test]
+    fn test_recipe_category_empty_ok() -> Result<()> {
+        let fx_json = r#"{"@type": "Recipe", "recipeCategory": [" , "]}"#;
+
+        let got: RecipeSchema = serde_json::from_str(fx_json)?;
+
+        pretty_assertions::assert_eq!(got.recipe_category.values(), ["uncategorized"]);
+        Ok(())
+    }
+
+    #[test]
+    fn test_recipe_cuisine_empty_ok() -> Result<()> {
+        for fx in [r#""""#, r#"" , ""#, r#"[" "]"#] {
+            let fx_json = format!(r#"{{"@type": "Recipe", "recipeCuisine": {fx}}}"#);
+
+            let got: RecipeSchema = serde_json::from_str(&fx_json)?;
+
+            assert_eq!(got.recipe_cuisine, None, "cuisine {fx}");
+        }
+        Ok(())
+    }
+
+    #[test]
+    fn test_recipe_serialize_round_trip_ok() -> Result<()> {
+        let fx_json = r#"{
+            "@context": "http://schema.org/",
+            "@type": "Recipe",
+            "name": "Pea soup",
+            "author": {"@type": "Organization", "name": "Soups Inc.", "url": "https://soups.example/"},
+            "datePublished": "2024-03-01T10:30:00+01:00",
+            "description": "A thick soup.",
+            "image": {"@type": "ImageObject", "url": "https://soups.example/pea.jpg", "width": 1200, "height": "800px"},
+            "keywords": "peas, soup",
+            "cookTime": "PT1H30M",
+            "prepTime": "PT15M",
+            "isAccessibleForFree": "True",
+            "recipeCategory": ["Soup", "Dinner"],
+            "recipeCuisine": "Dutch",
+            "recipeIngredient": ["500 g dried peas", "1 onion"],
+            "recipeInstructions": [
+                {"@type": "HowToSection", "name": "Soup", "itemListElement": [
+                    {"@type": "HowToStep", "text": "Soak the peas."},
+                    {"@type": "HowToStep", "text": "Simmer for an hour."}
+                ]}
+            ],
+            "recipeYield": "6",
+            "suitableForDiet": "VeganDiet",
+            "nutrition": {"@type": "NutritionInformation", "calories": "180 kcal", "fatContent": "1.5 g", "sodiumContent": "300mg"},
+            "aggregateRating": {"@type": "AggregateRating", "ratingValue": 4, "ratingCount": 12}
+        }"#;
+        let want: RecipeSchema = serde_json::from_str(fx_json)?;
+
+        let got: RecipeSchema = serde_json::from_str(&serde_json::to_string(&want)?)?;
+
+        pretty_assertions::assert_eq!(got, want);
+        Ok(())
+    }
+
+    #[test]
+    fn test_recipe_serialize_json_ld_ok() -> Result<()> {
+        let fx_json = r#"{
+            "name": "Pea soup",
+            "totalTime": "PT1H45M",
+            "recipeYield": 4,
+            "nutrition": {"calories": 180, "proteinContent": "12 grams"}
+        }"#;
+        let fx_recipe: RecipeSchema = serde_json::from_str(fx_json)?;
+
+        let got = serde_json::to_value(&fx_recipe)?;
+
+        let want = serde_json::json!({
+            "@context": "https://schema.org",
+            "@type": "Recipe",
+            "name": "Pea soup",
+            "totalTime": "PT1H45M",
+            "recipeYield": 4,
+            "nutrition": {"calories": "180 kcal", "proteinContent": "12 g"}
+        });
+        pretty_assertions::assert_eq!(got, want);
+        Ok(())
+    }
+}
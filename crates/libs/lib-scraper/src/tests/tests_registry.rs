@@ -0,0 +1,111 @@
+#[cfg(test)]
+mod tests {
+    use crate::{
+        tests::support::ScraperBuilder, websites::Website, Error, ParseMode, Registry,
+        SelectorExtractor, WebsiteEntry,
+    };
+
+    type Result<T> = core::result::Result<T, Box<dyn std::error::Error>>;
+
+    const FX_TOML: &str = r##"
+        [[websites]]
+        host = "www.allrecipes.com"
+        aliases = ["allrecipes.com", "m.allrecipes.com"]
+
+        [[websites]]
+        host = "family-recipes.example"
+        aliases = ["m.family-recipes.example"]
+        url = "https://family-recipes.example/recipes"
+
+        [websites.selectors]
+        name = "h1.title"
+        ingredients = "#ingredients li"
+    "##;
+
+    #[test]
+    fn test_registry_aliases_ok() -> Result<()> {
+        let registry = Registry::from_toml(FX_TOML)?;
+
+        let fx_cases = [
+            (
+                "https://m.allrecipes.com/recipe/1",
+                Some(Website::AllRecipesDotCom),
+            ),
+            (
+                "https://m.family-recipes.example/pea-soup",
+                Some(Website::Custom("family-recipes.example".to_string())),
+            ),
+            ("https://www.family-recipes.example/soup", None),
+        ];
+        for (fx_url, want) in fx_cases {
+            assert_eq!(registry.website(fx_url), want, "{fx_url}");
+        }
+        Ok(())
+    }
+
+    #[test]
+    fn test_registry_formats_ok() -> Result<()> {
+        let fx_json = r##"{"websites": [{
+            "host": "family-recipes.example",
+            "aliases": ["m.family-recipes.example"],
+            "url": "https://family-recipes.example/recipes",
+            "selectors": {"name": "h1.title", "ingredients": "#ingredients li"}
+        }]}"##;
+
+        let from_json = Registry::from_json(fx_json)?;
+        let from_toml = Registry::from_toml(FX_TOML)?;
+
+        let want = (
+            "family-recipes.example".to_string(),
+            "https://family-recipes.example/recipes".to_string(),
+        );
+        assert!(from_json.websites().contains(&want));
+        assert!(from_toml.websites().contains(&want));
+        assert!(from_toml.websites().contains(&(
+            "allrecipes.com".to_string(),
+            "https://www.allrecipes.com".to_string()
+        )));
+        assert_eq!(from_json.websites(), from_toml.websites());
+        Ok(())
+    }
+
+    #[test]
+    fn test_registry_invalid_err() -> Result<()> {
+        let got = Registry::new(vec![WebsiteEntry {
+            host: "family-recipes.example".to_string(),
+            selectors: Some(SelectorExtractor {
+                name: Some("h1[".to_string()),
+                ..Default::default()
+            }),
+            ..Default::default()
+        }]);
+        assert!(matches!(got, Err(Error::Select(_))));
+
+        let got = Registry::from_toml("[[websites]]\nhost = \"a.com\"\nalias = [\"b.com\"]");
+        assert!(matches!(got, Err(Error::Toml(_))));
+        Ok(())
+    }
+
+    #[test]
+    fn test_scrape_registered_website_ok() -> Result<()> {
+        let registry = Registry::from_toml(FX_TOML)?;
+        let scraper = ScraperBuilder::page(
+            r#"<h1 class="title">Cookbook:Pea Soup</h1>
+                <ul id="ingredients"><li>1 cup dried peas</li><li>1 onion</li></ul>"#,
+        )
+        .mode(ParseMode::Lenient)
+        .registry(registry)
+        .build();
+
+        let (got, got_report) =
+            scraper.scrape_with_report("https://m.family-recipes.example/pea-soup")?;
+
+        assert_eq!(got.name, Some("Cookbook:Pea Soup".to_string()));
+        assert_eq!(
+            got.recipe_ingredient,
+            Some(vec!["1 cup dried peas".to_string(), "1 onion".to_string()])
+        );
+        assert!(!got_report.unverified_site);
+        Ok(())
+    }
+}
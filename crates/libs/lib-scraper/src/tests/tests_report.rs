@@ -0,0 +1,165 @@
+#[cfg(test)]
+mod tests {
+    use crate::{
+        lenient,
+        schema::{nutrition::*, recipe::RecipeSchema, AtType},
+        tests::support::ScraperBuilder,
+        Error, FieldError, ParseMode, ScrapeReport, Scraper,
+    };
+
+    type Result<T> = core::result::Result<T, Box<dyn std::error::Error>>;
+
+    const FX_JSON: &str = r#"{
+        "@context": "https://schema.org",
+        "@type": ["Recipe", "NewsArticle"],
+        "name": "Lentil soup",
+        "datePublished": "last week",
+        "sponsoredBy": "Lentils Inc.",
+        "nutrition": {"@type": "NutritionInformation", "calories": "180 kcal", "fooContent": "1 g"}
+    }"#;
+
+    fn fx_scraper(mode: ParseMode, html: &str) -> Scraper {
+        ScraperBuilder::page(html).mode(mode).build()
+    }
+
+    #[test]
+    fn test_lenient_parse_ok() -> Result<()> {
+        let mut got_report = ScrapeReport::default();
+
+        let got = lenient::parse_recipes(FX_JSON, &mut got_report);
+
+        let want = RecipeSchema {
+            at_type: Some(AtType::Recipe),
+            name: Some("Lentil soup".to_string()),
+            nutrition: Some(NutritionInformationSchema {
+                at_type: Some(AtType::NutritionInformation),
+                calories: Some(Energy::Value(180.0, EnergyUnit::Kilocalorie)),
+                ..Default::default()
+            }),
+            ..Default::default()
+        };
+        pretty_assertions::assert_eq!(got, vec![want]);
+        pretty_assertions::assert_eq!(
+            got_report.unknown_keys,
+            vec![
+                "nutrition.fooContent".to_string(),
+                "sponsoredBy".to_string()
+            ]
+        );
+        assert_eq!(got_report.failed_fields.len(), 1);
+        assert_eq!(got_report.failed_fields[0].path, "datePublished");
+        assert_eq!(got_report.failed_fields[0].snippet, r#""last week""#);
+        Ok(())
+    }
+
+    #[test]
+    fn test_lenient_parse_graph_ok() -> Result<()> {
+        let fx_json = r#"{"@graph": [
+            {"@type": "WebSite", "name": "Soups"},
+            {"@type": "Recipe", "name": "Pea soup", "isFamilyFriendly": true}
+        ]}"#;
+        let mut got_report = ScrapeReport::default();
+
+        let got = lenient::parse_recipes(fx_json, &mut got_report);
+
+        assert_eq!(got.len(), 1);
+        assert_eq!(got[0].name, Some("Pea soup".to_string()));
+        assert_eq!(
+            got_report.unknown_keys,
+            vec!["isFamilyFriendly".to_string()]
+        );
+        Ok(())
+    }
+
+    #[test]
+    fn test_lenient_parse_invalid_json_ok() -> Result<()> {
+        let mut got_report = ScrapeReport::default();
+
+        let got = lenient::parse_recipes("{\"name\": ", &mut got_report);
+
+        assert!(got.is_empty());
+        assert!(matches!(
+            got_report.failed_fields.as_slice(),
+            [FieldError { path, .. }] if path == "$"
+        ));
+        Ok(())
+    }
+
+    #[test]
+    fn test_scrape_modes_ok() -> Result<()> {
+        let fx_url = "https://www.allrecipes.com/recipe/1";
+        let fx_html = format!(r#"<script type="application/ld+json">{FX_JSON}</script>"#);
+
+        let (got, got_report) =
+            fx_scraper(ParseMode::Lenient, &fx_html).scrape_with_report(fx_url)?;
+        assert_eq!(got.name, Some("Lentil soup".to_string()));
+        assert!(!got_report.is_clean());
+
+        let got = fx_scraper(ParseMode::Strict, &fx_html).scrape_with_report(fx_url);
+        match got {
+            Err(Error::Deserialize { path, snippet, .. }) => {
+                assert_eq!(path, "datePublished");
+                assert!(snippet.starts_with(r#""last week""#), "{snippet}");
+            }
+            got => panic!("expected a deserialization error, got {got:?}"),
+        }
+        Ok(())
+    }
+
+    #[test]
+    fn test_scrape_errors_err() -> Result<()> {
+        let fx_url = "https://www.allrecipes.com/recipe/1";
+        let fx_cases = [
+            (
+                r#"<script type="application/ld+json">{"@type": "WebSite", "name": "Soups"}</script>"#,
+                "NotARecipe",
+            ),
+            (
+                r#"<div itemscope itemtype="https://schema.org/Person">Jane</div>"#,
+                "NotARecipe",
+            ),
+            (
+                "<html><head><title>Just a moment...</title></head></html>",
+                "Blocked",
+            ),
+            (
+                r#"<form><div class="g-recaptcha" data-sitekey="abc"></div></form>"#,
+                "Blocked",
+            ),
+            ("<h1>Soup</h1>", "NoStructuredData"),
+        ];
+
+        for mode in [ParseMode::Lenient, ParseMode::Strict] {
+            for (fx_html, want) in fx_cases {
+                let got = fx_scraper(mode, fx_html).scrape(fx_url);
+
+                let got = match got {
+                    Err(Error::NotARecipe) => "NotARecipe",
+                    Err(Error::Blocked) => "Blocked",
+                    Err(Error::NoStructuredData) => "NoStructuredData",
+                    got => panic!("unexpected result for {fx_html}: {got:?}"),
+                };
+                assert_eq!(got, want, "{mode:?}: {fx_html}");
+            }
+        }
+        Ok(())
+    }
+
+    #[test]
+    fn test_scrape_unknown_website_ok() -> Result<()> {
+        let fx_url = "https://www.unknown-recipes.example/recipe/1";
+        let fx_html = r#"<script type="application/ld+json">{"@type": "Recipe", "name": "Pea soup"}</script>"#;
+        let mut scraper = fx_scraper(ParseMode::Lenient, fx_html);
+
+        assert!(matches!(scraper.scrape(fx_url), Err(Error::UnknownWebsite)));
+
+        scraper.unknown_websites = true;
+        let (got, got_report) = scraper.scrape_with_report(fx_url)?;
+        assert_eq!(got.name, Some("Pea soup".to_string()));
+        assert!(got_report.unverified_site);
+
+        let (_, got_report) = scraper.scrape_with_report("https://www.allrecipes.com/recipe/1")?;
+        assert!(!got_report.unverified_site);
+        Ok(())
+    }
+}
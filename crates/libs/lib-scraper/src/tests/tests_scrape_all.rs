@@ -0,0 +1,67 @@
+#[cfg(test)]
+mod tests {
+    use crate::{tests::support::ScraperBuilder, ParseMode, Scraper};
+
+    type Result<T> = core::result::Result<T, Box<dyn std::error::Error>>;
+
+    const FX_URL: &str = "https://www.allrecipes.com/gallery/weeknight-dinners/#top";
+
+    fn fx_scraper(mode: ParseMode, html: &'static str) -> Scraper {
+        ScraperBuilder::page(html).mode(mode).build()
+    }
+
+    #[test]
+    fn test_scrape_all_graph_ok() -> Result<()> {
+        let fx_html = r#"<script type="application/ld+json">{"@graph": [
+            {"@type": "WebPage", "name": "10 weeknight dinners"},
+            {"@type": "Recipe", "@id": "https://www.allrecipes.com/recipe/1/#recipe", "name": "Pea soup"},
+            {"@type": "Recipe", "name": "Lentil soup"}
+        ]}</script>
+        <script type="application/ld+json">{"@type": "Recipe", "name": "Bean soup"}</script>"#;
+
+        for mode in [ParseMode::Lenient, ParseMode::Strict] {
+            let got = fx_scraper(mode, fx_html).scrape_all(FX_URL)?;
+
+            let got: Vec<(Option<&str>, Option<&str>)> = got
+                .iter()
+                .map(|recipe| (recipe.at_id.as_deref(), recipe.name.as_deref()))
+                .collect();
+            assert_eq!(
+                got,
+                vec![
+                    (
+                        Some("https://www.allrecipes.com/recipe/1/#recipe"),
+                        Some("Pea soup")
+                    ),
+                    (
+                        Some("https://www.allrecipes.com/gallery/weeknight-dinners/#recipe-2"),
+                        Some("Lentil soup")
+                    ),
+                    (
+                        Some("https://www.allrecipes.com/gallery/weeknight-dinners/#recipe-3"),
+                        Some("Bean soup")
+                    ),
+                ],
+                "{mode:?}"
+            );
+        }
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn test_scrape_all_microdata_ok() -> Result<()> {
+        let fx_html = r#"
+            <div itemscope itemtype="https://schema.org/Recipe"><h2 itemprop="name">Pea soup</h2></div>
+            <div itemscope itemtype="https://schema.org/Recipe"><h2 itemprop="name">Lentil soup</h2></div>"#;
+        let scraper = fx_scraper(ParseMode::Lenient, fx_html);
+
+        let got = scraper.scrape_all_async(FX_URL).await?;
+        let got_first = scraper.scrape_async(FX_URL).await?;
+
+        assert_eq!(got.len(), 2);
+        assert_eq!(got[1].name, Some("Lentil soup".to_string()));
+        assert_eq!(got_first.name, Some("Pea soup".to_string()));
+        assert_eq!(got_first.at_id, None);
+        Ok(())
+    }
+}
@@ -0,0 +1,67 @@
+#[cfg(test)]
+mod tests {
+    use std::sync::{
+        atomic::{AtomicUsize, Ordering},
+        Arc,
+    };
+
+    use crate::{
+        tests::support::ScraperBuilder, websites::Website, Error, HttpClient, ParseMode,
+        MAX_CONCURRENT_REQUESTS_PER_HOST,
+    };
+
+    type Result<T> = core::result::Result<T, Box<dyn std::error::Error>>;
+
+    #[derive(Default)]
+    struct CountingHttpClient {
+        in_flight: AtomicUsize,
+        max_in_flight: AtomicUsize,
+    }
+
+    #[async_trait::async_trait]
+    impl HttpClient for CountingHttpClient {
+        async fn get_async<'a>(&'a self, host: Website, url: &str) -> crate::Result<String> {
+            let n = self.in_flight.fetch_add(1, Ordering::SeqCst) + 1;
+            self.max_in_flight.fetch_max(n, Ordering::SeqCst);
+            for _ in 0..10 {
+                tokio::task::yield_now().await;
+            }
+            self.in_flight.fetch_sub(1, Ordering::SeqCst);
+            self.get(host, url)
+        }
+
+        fn get(&self, _host: Website, url: &str) -> crate::Result<String> {
+            Ok(format!(
+                r#"<script type="application/ld+json">{{"@context":"https://schema.org","@type":"Recipe","name":"{url}"}}</script>"#
+            ))
+        }
+    }
+
+    #[tokio::test]
+    async fn test_scrape_many_ok() -> Result<()> {
+        let fx_client = Arc::new(CountingHttpClient::default());
+        let fx_scraper = ScraperBuilder::new(fx_client.clone())
+            .mode(ParseMode::Strict)
+            .build();
+        let mut fx_urls: Vec<String> = (0..10)
+            .map(|i| format!("https://www.allrecipes.com/recipe/{i}"))
+            .collect();
+        fx_urls.push("https://www.unknown-recipes.example/recipe/1".to_string());
+
+        let got = fx_scraper.scrape_many(&fx_urls).await;
+
+        assert_eq!(got.len(), fx_urls.len());
+        for (url, res) in fx_urls.iter().zip(got.iter()).take(10) {
+            assert_eq!(
+                res.as_ref().map(|r| r.name.clone()).ok(),
+                Some(Some(url.clone()))
+            );
+        }
+        assert!(matches!(got[10], Err(Error::UnknownWebsite)));
+        assert_eq!(
+            fx_client.max_in_flight.load(Ordering::SeqCst),
+            MAX_CONCURRENT_REQUESTS_PER_HOST
+        );
+        Ok(())
+    }
+}
@@ -2,7 +2,10 @@
 mod tests {
     use crate::websites::Website;
     use crate::RecipeSchema;
-    use crate::{schema::*, tests::support::scrape};
+    use crate::{
+        schema::*,
+        tests::support::{scrape, scrape_normalized},
+    };
     use common::*;
     use iso8601::{
         Date::YMD,
@@ -17,7 +20,7 @@ mod tests {
 
     #[test]
     fn test_abuelascounter_dot_com() -> Result<()> {
-        let got = scrape(Website::AbuelasCounterDotCom, 0)?;
+        let got = scrape_normalized(Website::AbuelasCounterDotCom, 0)?;
 
         let want = RecipeSchema {
         at_context: AtContext::SchemaDotOrg,
@@ -40,9 +43,14 @@ mod tests {
             },
         })),
         description: Some(TextOrTextObject::Text("".to_string())),
-        image: Some(ImageObjectOrUrl::Url(Url::parse("https://abuelascounter.com/wp-content/uploads/2023/10/Roasted-Carrot-Soup-Recipe-480x270.jpeg").unwrap())),
+        image: Some(ImageObjectOrUrl::Images(vec![
+            ImageObjectOrUrl::Url(Url::parse("https://abuelascounter.com/wp-content/uploads/2023/10/Roasted-Carrot-Soup-Recipe.jpeg").unwrap()),
+            ImageObjectOrUrl::Url(Url::parse("https://abuelascounter.com/wp-content/uploads/2023/10/Roasted-Carrot-Soup-Recipe-500x500.jpeg").unwrap()),
+            ImageObjectOrUrl::Url(Url::parse("https://abuelascounter.com/wp-content/uploads/2023/10/Roasted-Carrot-Soup-Recipe-500x375.jpeg").unwrap()),
+            ImageObjectOrUrl::Url(Url::parse("https://abuelascounter.com/wp-content/uploads/2023/10/Roasted-Carrot-Soup-Recipe-480x270.jpeg").unwrap()),
+        ])),
         keywords: Some(DefinedTermOrTextOrUrl::Text(vec![
-            "abuelau0026#039;s".to_string(),
+            "abuela's".to_string(),
             "cuban".to_string(),
             "easy recipes".to_string(),
             "healthy recipes".to_string(),
@@ -55,17 +63,17 @@ mod tests {
         name: Some("Roasted Carrot Soup".to_string()),
         nutrition: Some(NutritionInformationSchema { at_type: Some(AtType::NutritionInformation), ..Default::default() }),
         prep_time: Some(YMDHMS { year: 0, month: 0, day: 0, hour: 0, minute: 10, second: 0, millisecond: 0 }),
-        recipe_category: RecipeCategory::Text("Soups".to_string()),
-        recipe_cuisine: Some(RecipeCuisine::Text("American".to_string())),
+        recipe_category: RecipeCategory::Texts(vec!["Soups".to_string()]),
+        recipe_cuisine: Some(RecipeCuisine::Texts(vec!["American".to_string()])),
         recipe_ingredient: Some(vec![
             "3 cups of carrots, that have been peeled and diced".to_string(),
-            "1 ½ cups of butternut squash, that has been peeled and diced".to_string(),
+            "1 1/2 cups of butternut squash, that has been peeled and diced".to_string(),
             "1 apple, peeled and diced (we like to use gala or granny smith apples)".to_string(),
             "3 shallots, cut in quarters".to_string(),
             "6 sprigs of thyme".to_string(),
             "4 tablespoons of olive oil or avocado oil".to_string(),
             "Freshly grated nutmeg".to_string(),
-            "3 ½ to 4 ½ cups of chicken or vegetable stock".to_string(),
+            "3 1/2 to 4 1/2 cups of chicken or vegetable stock".to_string(),
             "Salt and freshly cracked pepper".to_string(),
             "Garnish: chives, sour cream, Calabrian chili peppers".to_string(),
         ]),
@@ -107,8 +115,8 @@ mod tests {
             },
             HowTo {
                 at_type: AtType::HowToStep,
-                name: Some("Blend until completely smooth. Use a rubber spatula to move any chunks or pieces from the sides of the blender. If you want it to be a little thinner add another ½-1 cup of liquid or as much as you need to get it to your preferred consistency.".to_string()),
-                text: "Blend until completely smooth. Use a rubber spatula to move any chunks or pieces from the sides of the blender. If you want it to be a little thinner add another ½-1 cup of liquid or as much as you need to get it to your preferred consistency.".to_string(),
+                name: Some("Blend until completely smooth. Use a rubber spatula to move any chunks or pieces from the sides of the blender. If you want it to be a little thinner add another 1/2-1 cup of liquid or as much as you need to get it to your preferred consistency.".to_string()),
+                text: "Blend until completely smooth. Use a rubber spatula to move any chunks or pieces from the sides of the blender. If you want it to be a little thinner add another 1/2-1 cup of liquid or as much as you need to get it to your preferred consistency.".to_string(),
                 url: Some(Url::parse("https://abuelascounter.com/roasted-carrot-soup/#wpzoom-rcb-direction-step-16981142889011019").unwrap()),
                 image: Some(ImageObjectOrUrl::ImageObject(Box::default())),
             },
@@ -132,8 +140,8 @@ mod tests {
                 Url::parse("https://abuelascounter.com/wp-content/uploads/2023/10/Roasted-Carrot-Soup-Recipe-500x375.jpeg").unwrap(),
                 Url::parse("https://abuelascounter.com/wp-content/uploads/2023/10/Roasted-Carrot-Soup-Recipe-480x270.jpeg").unwrap(),
             ],
-            content_url: Url::parse("https://youtu.be/g63Nto5ld-k").unwrap(),
-            embed_url: Url::parse("https://youtu.be/g63Nto5ld-k").unwrap(),
+            content_url: Some(Url::parse("https://youtu.be/g63Nto5ld-k").unwrap()),
+            embed_url: Some(Url::parse("https://youtu.be/g63Nto5ld-k").unwrap()),
             duration: None,
             upload_date: Some(DateTime { date: YMD { year: 2023, month: 10, day: 24 }, time: Time { hour: 19, minute: 45, second: 56, millisecond: 0, tz_offset_hours: 0, tz_offset_minutes: 0 } }),
         }))),
@@ -185,21 +193,21 @@ mod tests {
         name: Some("Easy Shaved Brussels Sprouts".to_string()),
         nutrition: Some(NutritionInformationSchema {
             at_type: Some(AtType::Unspecified),
-            calories: Some(Energy::Str("149 calories".to_string())),
-            carbohydrate_content: Some(Mass::Str("14.6 g".to_string())),
-            cholesterol_content: Some(Mass::Str("3.6 mg".to_string())),
-            fat_content: Some(Mass::Str("9.2 g".to_string())),
-            fiber_content: Some(Mass::Str("6.5 g".to_string())),
-            protein_content: Some(Mass::Str("6.5 g".to_string())),
-            saturated_fat_content: Some(Mass::Str("2.1 g".to_string())),
-            sodium_content: Some(Mass::Str("271.1 mg".to_string())),
-            sugar_content: Some(Mass::Str("3 g".to_string())),
-            trans_fat_content: Some(Mass::Str("0 g".to_string())),
+            calories: Some(Energy::Value(149.0, EnergyUnit::Kilocalorie)),
+            carbohydrate_content: Some(Mass::Value(14.6, MassUnit::Gram)),
+            cholesterol_content: Some(Mass::Value(3.6, MassUnit::Milligram)),
+            fat_content: Some(Mass::Value(9.2, MassUnit::Gram)),
+            fiber_content: Some(Mass::Value(6.5, MassUnit::Gram)),
+            protein_content: Some(Mass::Value(6.5, MassUnit::Gram)),
+            saturated_fat_content: Some(Mass::Value(2.1, MassUnit::Gram)),
+            sodium_content: Some(Mass::Value(271.1, MassUnit::Milligram)),
+            sugar_content: Some(Mass::Value(3.0, MassUnit::Gram)),
+            trans_fat_content: Some(Mass::Value(0.0, MassUnit::Gram)),
             ..Default::default()
         }),
         prep_time: Some(YMDHMS { year: 0, month: 0, day: 0, hour: 0, minute: 10, second: 0, millisecond: 0 }),
-        recipe_category: RecipeCategory::Text("Side dish".to_string()),
-        recipe_cuisine: Some(RecipeCuisine::Text("Vegetables".to_string())),
+        recipe_category: RecipeCategory::Texts(vec!["Side dish".to_string()]),
+        recipe_cuisine: Some(RecipeCuisine::Texts(vec!["Vegetables".to_string()])),
         recipe_ingredient: Option::from(vec![
             "1 pound Brussels sprouts (off the stalk)".to_string(),
             "2 cloves garlic, minced".to_string(),
@@ -385,8 +393,8 @@ mod tests {
                 },
             ),
         ),
-        recipe_category: RecipeCategory::Text("Carne".to_string()),
-        recipe_cuisine: Some(RecipeCuisine::Text("Brasileira".to_string())),
+        recipe_category: RecipeCategory::Texts(vec!["Carne".to_string()]),
+        recipe_cuisine: Some(RecipeCuisine::Texts(vec!["Brasileira".to_string()])),
         recipe_ingredient: Some(vec![
             "500 gramas de alcatra cortada em tirinhas".to_string(),
             "1/4 xícara (chá) de manteiga ".to_string(),
@@ -69,8 +69,8 @@ mod tests {
             }))),
             ..Default::default()
         })),
-        recipe_category: RecipeCategory::Text("Carne".to_string()),
-        recipe_cuisine: Some(RecipeCuisine::Text("Brasileira".to_string())),
+        recipe_category: RecipeCategory::Texts(vec!["Carne".to_string()]),
+        recipe_cuisine: Some(RecipeCuisine::Texts(vec!["Brasileira".to_string()])),
         recipe_ingredient: Some(vec![
             "500 gramas de alcatra cortada em tirinhas".to_string(),
             "1/4 xícara (chá) de manteiga ".to_string(),
@@ -0,0 +1,92 @@
+#[cfg(test)]
+mod tests {
+    use crate::{
+        schema::{
+            common::{QuantitativeValueOrText, QuantitativeValueType},
+            recipe::RecipeSchema,
+        },
+        servings::{parse_yield, RecipeYield},
+    };
+
+    type Result<T> = core::result::Result<T, Box<dyn std::error::Error>>;
+
+    fn fx_yield(servings: u16, range: Option<(u16, u16)>, unit_label: Option<&str>) -> RecipeYield {
+        RecipeYield {
+            servings: Some(servings),
+            range,
+            unit_label: unit_label.map(str::to_string),
+        }
+    }
+
+    #[test]
+    fn test_parse_yield_ok() {
+        let fx_cases = [
+            ("Makes 24 cookies", fx_yield(24, None, Some("cookies"))),
+            ("4-6 servings", fx_yield(4, Some((4, 6)), Some("servings"))),
+            ("4 to 6", fx_yield(4, Some((4, 6)), None)),
+            ("1 loaf (12 slices)", fx_yield(12, None, Some("slices"))),
+            ("Serves 4 (as a main)", fx_yield(4, None, Some("servings"))),
+            ("Servings: 8", fx_yield(8, None, Some("servings"))),
+            ("2 Dozen muffins", fx_yield(24, None, Some("muffins"))),
+            ("Makes one loaf", fx_yield(1, None, Some("loaf"))),
+            ("4.0 servings", fx_yield(4, None, Some("servings"))),
+            (
+                "6 à 8 personnes",
+                fx_yield(6, Some((6, 8)), Some("personnes")),
+            ),
+        ];
+
+        for (fx, want) in fx_cases {
+            assert_eq!(parse_yield(fx), want, "parsing {fx:?}");
+        }
+    }
+
+    #[test]
+    fn test_parse_yield_none_ok() {
+        for fx in ["", "A crowd", "0 servings", "100000 cookies"] {
+            assert_eq!(parse_yield(fx).servings, None, "parsing {fx:?}");
+        }
+    }
+
+    #[test]
+    fn test_parse_yield_decimal_ok() {
+        let got = parse_yield("4.5");
+        assert_eq!(got, RecipeYield::default());
+
+        let got = parse_yield("1,5 liters");
+        assert_eq!(
+            got,
+            RecipeYield {
+                unit_label: Some("liters".to_string()),
+                ..Default::default()
+            }
+        );
+    }
+
+    #[test]
+    fn test_parsed_yield_ok() -> Result<()> {
+        let got: RecipeSchema =
+            serde_json::from_str(r#"{"@type": "Recipe", "recipeYield": ["4", "4 servings"]}"#)?;
+        assert_eq!(got.parsed_yield(), fx_yield(4, None, Some("servings")));
+
+        let got: RecipeSchema =
+            serde_json::from_str(r#"{"@type": "Recipe", "recipeYield": [4, "4 servings"]}"#)?;
+        assert_eq!(got.parsed_yield(), fx_yield(4, None, Some("servings")));
+
+        let got: RecipeSchema = serde_json::from_str(r#"{"@type": "Recipe", "recipeYield": [6]}"#)?;
+        assert_eq!(got.parsed_yield(), fx_yield(6, None, None));
+
+        let got: RecipeSchema =
+            serde_json::from_str(r#"{"@type": "Recipe", "recipeYield": ["1 loaf (12 slices)"]}"#)?;
+        assert_eq!(got.parsed_yield(), fx_yield(12, None, Some("slices")));
+
+        let got = RecipeSchema {
+            recipe_yield: QuantitativeValueOrText::QuantitativeValue(QuantitativeValueType {
+                value: 6,
+            }),
+            ..Default::default()
+        };
+        assert_eq!(got.parsed_yield(), fx_yield(6, None, None));
+        Ok(())
+    }
+}
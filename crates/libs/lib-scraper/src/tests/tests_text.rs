@@ -0,0 +1,127 @@
+#[cfg(test)]
+mod tests {
+    use crate::{
+        schema::{
+            common::{
+                CreativeWorkOrItemListOrText, DefinedTermOrTextOrUrl, HowTo, TextOrTextObject,
+            },
+            recipe::{RecipeCategory, RecipeCuisine, RecipeSchema},
+            AtType,
+        },
+        text::clean,
+        ScraperConfig,
+    };
+
+    type Result<T> = core::result::Result<T, Box<dyn std::error::Error>>;
+
+    #[test]
+    fn test_clean_ok() {
+        let fx_cases = [
+            ("abuelau0026#039;s", "abuela's"),
+            (r"abuela&#039;s", "abuela's"),
+            ("Salt &amp;amp; pepper", "Salt & pepper"),
+            ("<p>A thick&nbsp;soup.</p>", "A thick soup."),
+            ("<p>A thick soup.</p><p>Serve hot.</p>", "A thick soup. Serve hot."),
+            ("Soak.<br>Boil.", "Soak. Boil."),
+            ("&lt;p&gt;Escaped tags&lt;/p&gt;", "Escaped tags"),
+            ("  Split\u{a0}\u{a0}peas\u{200b} \n", "Split peas"),
+            ("1½ cups of milk", "1 1/2 cups of milk"),
+            ("“Grandma’s” soup", "\"Grandma's\" soup"),
+            ("Tom & Jerry", "Tom & Jerry"),
+        ];
+
+        for (fx, want) in fx_cases {
+            assert_eq!(clean(fx), want, "cleaning {fx:?}");
+        }
+    }
+
+    #[test]
+    fn test_normalize_text_ok() {
+        let mut got = RecipeSchema {
+            name: Some("Grandma&#8217;s&nbsp;soup".to_string()),
+            description: Some(TextOrTextObject::Text(
+                "<p>A <strong>thick</strong> soup.</p>".to_string(),
+            )),
+            keywords: Some(DefinedTermOrTextOrUrl::Text(
+                "soup, abuelau0026#039;s".to_string(),
+            )),
+            recipe_category: RecipeCategory::Texts(vec!["Soups &amp;amp; stews".to_string()]),
+            recipe_ingredient: Some(vec!["½ cup split peas".to_string(), "&nbsp;".to_string()]),
+            recipe_instructions: Some(CreativeWorkOrItemListOrText::ItemList(vec![HowTo {
+                at_type: AtType::HowToStep,
+                text: "Boil&hellip; <em>slowly</em>.".to_string(),
+                ..Default::default()
+            }])),
+            ..Default::default()
+        };
+
+        got.normalize_text();
+
+        let want = RecipeSchema {
+            name: Some("Grandma's soup".to_string()),
+            description: Some(TextOrTextObject::Text("A thick soup.".to_string())),
+            keywords: Some(DefinedTermOrTextOrUrl::Text("soup, abuela's".to_string())),
+            recipe_category: RecipeCategory::Texts(vec!["Soups & stews".to_string()]),
+            recipe_ingredient: Some(vec!["1/2 cup split peas".to_string()]),
+            recipe_instructions: Some(CreativeWorkOrItemListOrText::ItemList(vec![HowTo {
+                at_type: AtType::HowToStep,
+                text: "Boil… slowly.".to_string(),
+                ..Default::default()
+            }])),
+            ..Default::default()
+        };
+        pretty_assertions::assert_eq!(got, want);
+    }
+
+    #[test]
+    fn test_normalize_text_empty_terms_ok() {
+        let mut got = RecipeSchema {
+            recipe_category: RecipeCategory::Texts(vec!["&nbsp;".to_string()]),
+            recipe_cuisine: Some(RecipeCuisine::Texts(vec!["<br>".to_string()])),
+            ..Default::default()
+        };
+
+        got.normalize_text();
+
+        assert_eq!(got.recipe_category.values(), ["uncategorized"]);
+        assert_eq!(got.recipe_cuisine, None);
+    }
+
+    #[test]
+    fn test_normalize_text_keeps_lines_ok() {
+        let mut got = RecipeSchema {
+            recipe_instructions: Some(CreativeWorkOrItemListOrText::Text(
+                "<p>Soak the peas.</p><p>Boil&nbsp;them.<br>Serve.</p>".to_string(),
+            )),
+            ..Default::default()
+        };
+
+        got.normalize_text();
+
+        assert_eq!(
+            got.recipe_instructions,
+            Some(CreativeWorkOrItemListOrText::Text(
+                "Soak the peas.\nBoil them.\nServe.".to_string()
+            ))
+        );
+    }
+
+    #[test]
+    fn test_text_normalization_disabled_ok() -> Result<()> {
+        let fx_html = r#"<script type="application/ld+json">
+            {"@type": "Recipe", "name": "Grandma’s  soup"}
+        </script>"#;
+
+        let got = ScraperConfig::default()
+            .text_normalization(false)
+            .build()?
+            .parse_html(fx_html, None)?;
+        assert_eq!(got.name, Some("Grandma’s  soup".to_string()));
+
+        let got = ScraperConfig::default()
+            .build()?
+            .parse_html(fx_html, None)?;
+        assert_eq!(got.name, Some("Grandma's soup".to_string()));
+        Ok(())
+    }
+}
@@ -0,0 +1,170 @@
+#[cfg(test)]
+mod tests {
+    use iso8601::Duration::YMDHMS;
+    use std::path::Path;
+    use url::Url;
+
+    use crate::{
+        schema::recipe::RecipeSchema,
+        video::{RecipeVideo, VideoHost},
+        Error,
+    };
+
+    type Result<T> = core::result::Result<T, Box<dyn std::error::Error>>;
+
+    fn fx_recipe(video: &str) -> Result<RecipeSchema> {
+        Ok(serde_json::from_str(&format!(
+            r#"{{"@type": "Recipe", "video": {video}}}"#
+        ))?)
+    }
+
+    #[test]
+    fn test_recipe_video_youtube_ok() -> Result<()> {
+        let fx = fx_recipe(
+            r#"{
+                "@type": "VideoObject",
+                "name": "Roasted Carrot Soup",
+                "description": "Roasted Carrot Soup",
+                "thumbnailUrl": ["https://abuelascounter.com/soup.jpeg"],
+                "contentUrl": "https://youtu.be/g63Nto5ld-k",
+                "embedUrl": "https://www.youtube.com/watch?v=g63Nto5ld-k&t=10",
+                "duration": "PT4M2S"
+            }"#,
+        )?;
+
+        let want = RecipeVideo {
+            name: Some("Roasted Carrot Soup".to_string()),
+            content_url: None,
+            embed_url: Some(Url::parse("https://www.youtube.com/embed/g63Nto5ld-k")?),
+            duration: Some(YMDHMS {
+                year: 0,
+                month: 0,
+                day: 0,
+                hour: 0,
+                minute: 4,
+                second: 2,
+                millisecond: 0,
+            }),
+            host: VideoHost::YouTube {
+                id: "g63Nto5ld-k".to_string(),
+            },
+        };
+        pretty_assertions::assert_eq!(fx.recipe_video(), Some(want));
+        Ok(())
+    }
+
+    #[test]
+    fn test_recipe_video_vimeo_and_file_ok() -> Result<()> {
+        let fx = fx_recipe(
+            r#"{
+                "@type": "VideoObject",
+                "name": "Pea soup",
+                "description": "",
+                "contentUrl": "https://cdn.soupes.example/videos/pea-soup.MP4",
+                "embedUrl": "https://vimeo.com/channels/soups/76979871"
+            }"#,
+        )?;
+
+        let got = fx.recipe_video().ok_or("no video")?;
+
+        assert_eq!(
+            got.embed_url,
+            Some(Url::parse("https://player.vimeo.com/video/76979871")?)
+        );
+        assert_eq!(
+            got.content_url,
+            Some(Url::parse(
+                "https://cdn.soupes.example/videos/pea-soup.MP4"
+            )?)
+        );
+        assert_eq!(
+            got.host,
+            VideoHost::Vimeo {
+                id: "76979871".to_string()
+            }
+        );
+        Ok(())
+    }
+
+    #[test]
+    fn test_recipe_video_none_ok() -> Result<()> {
+        let fx = fx_recipe(
+            r#"{
+                "@type": "VideoObject",
+                "name": "Pea soup",
+                "description": "",
+                "contentUrl": "https://soupes.example/pea-soup"
+            }"#,
+        )?;
+        assert_eq!(fx.recipe_video().map(|video| video.host), None);
+
+        let fx = fx_recipe(
+            r#"{
+                "@type": "VideoObject",
+                "name": "Pea soup",
+                "description": "",
+                "contentUrl": "file:///videos/pea-soup.mp4"
+            }"#,
+        )?;
+        assert_eq!(fx.recipe_video(), None);
+
+        assert_eq!(RecipeSchema::default().recipe_video(), None);
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn test_download_without_file_err() -> Result<()> {
+        let fx = RecipeVideo {
+            name: None,
+            content_url: None,
+            embed_url: Some(Url::parse("https://www.youtube.com/embed/g63Nto5ld-k")?),
+            duration: None,
+            host: VideoHost::YouTube {
+                id: "g63Nto5ld-k".to_string(),
+            },
+        };
+
+        let got = fx.download(Path::new("video.mp4")).await;
+
+        assert!(matches!(got, Err(Error::NoVideoFile)), "{got:?}");
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn test_download_local_file_err() -> Result<()> {
+        let fx = RecipeVideo {
+            name: None,
+            content_url: Some(Url::parse("file:///etc/passwd.mp4")?),
+            embed_url: None,
+            duration: None,
+            host: VideoHost::Other,
+        };
+
+        let got = fx.download(Path::new("video.mp4")).await;
+
+        assert!(matches!(got, Err(Error::NoVideoFile)), "{got:?}");
+        Ok(())
+    }
+
+    #[test]
+    fn test_file_extension_ok() -> Result<()> {
+        let fx_cases = [
+            ("https://soupes.example/videos/pea-soup.MP4", Some("mp4")),
+            ("https://soupes.example/videos/pea-soup.webm", Some("webm")),
+            ("https://soupes.example/live/playlist.m3u8", Some("mp4")),
+            ("https://soupes.example/pea-soup", None),
+        ];
+
+        for (fx, want) in fx_cases {
+            let video = RecipeVideo {
+                name: None,
+                content_url: Some(Url::parse(fx)?),
+                embed_url: None,
+                duration: None,
+                host: VideoHost::Other,
+            };
+            assert_eq!(video.file_extension(), want, "{fx}");
+        }
+        Ok(())
+    }
+}
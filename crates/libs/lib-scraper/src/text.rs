@@ -0,0 +1,175 @@
+//! Cleaning of the text of scraped recipes.
+//!
+//! Websites escape their text twice, leave broken `u0026`-style escapes behind, embed HTML
+//! tags in plain text fields and pad them with non-breaking spaces. The cleaning decodes the
+//! entities, strips the tags, collapses the whitespace and spells out the unicode fractions
+//! and typographic quotes.
+
+use regex::{Captures, Regex};
+use std::sync::OnceLock;
+
+use crate::{
+    ingredients::replace_unicode_fractions,
+    instructions::{line_break_regex, strip_html},
+    schema::{
+        common::{
+            CreativeWorkOrItemListOrText, DefinedTermOrTextOrUrl, HowTo, QuantitativeValueOrText,
+            TextOrTextObject,
+        },
+        recipe::{RecipeCategory, RecipeCuisine, RecipeSchema},
+    },
+};
+
+/// The number of times entities are decoded, for text escaped more than once.
+const MAX_UNESCAPES: usize = 3;
+
+impl RecipeSchema {
+    /// Cleans the text fields of the recipe: names, description, keywords, ingredients,
+    /// instructions, categories, cuisines, yields and serving size.
+    ///
+    /// Ingredients left empty are removed. The steps of instructions given as a block of text
+    /// stay on their own line.
+    pub fn normalize_text(&mut self) {
+        for field in [
+            &mut self.name,
+            &mut self.alternate_name,
+            &mut self.headline,
+            &mut self.cooking_method,
+        ] {
+            clean_option(field);
+        }
+
+        if let Some(TextOrTextObject::Text(description)) = &mut self.description {
+            *description = clean(description);
+        }
+
+        if let Some(DefinedTermOrTextOrUrl::Text(keywords)) = &mut self.keywords {
+            *keywords = clean(keywords);
+        }
+
+        if let Some(author) = &mut self.author {
+            clean_option(&mut author.name);
+        }
+
+        if let Some(ingredients) = &mut self.recipe_ingredient {
+            *ingredients = ingredients
+                .iter()
+                .map(|ingredient| clean(ingredient))
+                .filter(|ingredient| !ingredient.is_empty())
+                .collect();
+        }
+
+        match &mut self.recipe_instructions {
+            Some(CreativeWorkOrItemListOrText::Text(text)) => *text = clean_lines(text),
+            Some(CreativeWorkOrItemListOrText::ItemList(steps)) => {
+                steps.iter_mut().for_each(clean_how_to)
+            }
+            Some(CreativeWorkOrItemListOrText::Sections(sections)) => {
+                for section in sections {
+                    clean_option(&mut section.name);
+                    section.item_list_element.iter_mut().for_each(clean_how_to);
+                }
+            }
+            Some(CreativeWorkOrItemListOrText::CreativeWork(work)) => {
+                clean_option(&mut work.name);
+                if let Some(TextOrTextObject::Text(text)) = &mut work.description {
+                    *text = clean_lines(text);
+                }
+            }
+            None => {}
+        }
+
+        let RecipeCategory::Texts(categories) = &mut self.recipe_category;
+        clean_terms(categories);
+        self.recipe_category = RecipeCategory::from_terms(std::mem::take(categories));
+
+        if let Some(RecipeCuisine::Texts(cuisines)) = &mut self.recipe_cuisine {
+            clean_terms(cuisines);
+            if cuisines.is_empty() {
+                self.recipe_cuisine = None;
+            }
+        }
+
+        if let QuantitativeValueOrText::Text(recipe_yield) = &mut self.recipe_yield {
+            *recipe_yield = clean(recipe_yield);
+        }
+        if let Some(QuantitativeValueOrText::Text(total_yield)) = &mut self.total_yield {
+            *total_yield = clean(total_yield);
+        }
+
+        if let Some(nutrition) = &mut self.nutrition {
+            clean_option(&mut nutrition.serving_size);
+        }
+    }
+}
+
+/// Cleans a single line of text.
+pub(crate) fn clean(s: &str) -> String {
+    let mut text = fix_broken_escapes(s);
+    for _ in 0..MAX_UNESCAPES {
+        let unescaped = strip_html(&text);
+        if unescaped == text {
+            break;
+        }
+        text = unescaped;
+    }
+
+    let text: String = replace_unicode_fractions(&text)
+        .chars()
+        .filter_map(|c| match c {
+            '\u{200b}' | '\u{200c}' | '\u{200d}' | '\u{feff}' | '\u{ad}' => None,
+            '‘' | '’' | '‚' | '‛' | '′' => Some('\''),
+            '“' | '”' | '„' | '‟' | '″' => Some('"'),
+            c => Some(c),
+        })
+        .collect();
+
+    text.split_whitespace().collect::<Vec<_>>().join(" ")
+}
+
+/// Cleans every line of a block of text, where lines are also delimited by `<br>` and
+/// closing paragraph tags. Empty lines are dropped.
+fn clean_lines(s: &str) -> String {
+    line_break_regex()
+        .replace_all(&fix_broken_escapes(s), "\n")
+        .lines()
+        .map(clean)
+        .filter(|line| !line.is_empty())
+        .collect::<Vec<_>>()
+        .join("\n")
+}
+
+fn clean_option(field: &mut Option<String>) {
+    if let Some(text) = field {
+        *text = clean(text);
+    }
+}
+
+fn clean_how_to(how_to: &mut HowTo) {
+    how_to.text = clean(&how_to.text);
+    clean_option(&mut how_to.name);
+}
+
+fn clean_terms(terms: &mut Vec<String>) {
+    *terms = terms
+        .iter()
+        .map(|term| clean(term))
+        .filter(|term| !term.is_empty())
+        .collect();
+}
+
+/// Restores the escapes that lost their backslash, e.g. "u0026" for "&", along the way.
+fn fix_broken_escapes(s: &str) -> String {
+    static INSTANCE: OnceLock<Regex> = OnceLock::new();
+
+    if !s.contains("u00") {
+        return s.to_string();
+    }
+
+    let re = INSTANCE.get_or_init(|| Regex::new(r"\\?u00(22|26|27|3[cCeE])").unwrap());
+    re.replace_all(s, |caps: &Captures| {
+        let code = u32::from_str_radix(&caps[1], 16).unwrap_or_default();
+        char::from_u32(code).unwrap_or_default().to_string()
+    })
+    .into_owned()
+}
@@ -0,0 +1,205 @@
+//! Normalization of the video of a recipe.
+//!
+//! Websites give the page of the video, its player or the video file itself in either
+//! `contentUrl` or `embedUrl`. The player of YouTube and Vimeo videos is rebuilt from the ID of
+//! the video, and only the URLs of actual video files are kept as the content URL.
+
+use std::{
+    path::{Path, PathBuf},
+    process::Stdio,
+    time::Duration,
+};
+use tokio::{process::Command, time::timeout};
+use url::Url;
+
+use crate::{
+    schema::{common::ClipOrVideoObject, recipe::RecipeSchema},
+    Error, Result,
+};
+
+/// The extensions of the video files and streams ffmpeg can download.
+const VIDEO_EXTENSIONS: [&str; 6] = ["mp4", "m4v", "mov", "webm", "m3u8", "mkv"];
+
+/// How long a download may take before ffmpeg is stopped, e.g. on a live stream that never
+/// ends.
+pub const MAX_DOWNLOAD_DURATION: Duration = Duration::from_secs(15 * 60);
+
+/// The video of a recipe.
+#[derive(Clone, Debug, PartialEq)]
+pub struct RecipeVideo {
+    /// The title of the video.
+    pub name: Option<String>,
+
+    /// The URL of the video file, e.g. an MP4 file or an HLS playlist.
+    pub content_url: Option<Url>,
+
+    /// The URL of the player of the video, e.g. `https://www.youtube.com/embed/g63Nto5ld-k`.
+    pub embed_url: Option<Url>,
+
+    /// How long the video lasts.
+    pub duration: Option<iso8601::Duration>,
+
+    /// Where the video is hosted.
+    pub host: VideoHost,
+}
+
+/// Where the video of a recipe is hosted.
+#[derive(Clone, Debug, PartialEq)]
+pub enum VideoHost {
+    YouTube {
+        id: String,
+    },
+    Vimeo {
+        id: String,
+    },
+
+    /// The website of the recipe or a video hosting service that is not recognized.
+    Other,
+}
+
+impl RecipeSchema {
+    /// The video of the recipe, when it has one that can be played or downloaded.
+    pub fn recipe_video(&self) -> Option<RecipeVideo> {
+        let Some(ClipOrVideoObject::VideoObject(video)) = &self.video else {
+            return None;
+        };
+
+        let urls = [&video.embed_url, &video.content_url];
+        let host = urls
+            .iter()
+            .filter_map(|url| url.as_ref())
+            .find_map(video_host)
+            .unwrap_or(VideoHost::Other);
+
+        let embed_url = match &host {
+            VideoHost::YouTube { id } => {
+                Url::parse(&format!("https://www.youtube.com/embed/{id}")).ok()
+            }
+            VideoHost::Vimeo { id } => {
+                Url::parse(&format!("https://player.vimeo.com/video/{id}")).ok()
+            }
+            VideoHost::Other => video.embed_url.clone().filter(|url| !is_video_file(url)),
+        };
+        let content_url = urls
+            .into_iter()
+            .flatten()
+            .find(|url| is_video_file(url))
+            .cloned();
+
+        if embed_url.is_none() && content_url.is_none() {
+            return None;
+        }
+
+        Some(RecipeVideo {
+            name: Some(video.name.trim().to_string()).filter(|name| !name.is_empty()),
+            content_url,
+            embed_url,
+            duration: video.duration,
+            host,
+        })
+    }
+}
+
+impl RecipeVideo {
+    /// The extension of the file the video downloads to, e.g. `webm`. HLS streams are saved
+    /// as `mp4`. Returns `None` when the video has no file to download.
+    pub fn file_extension(&self) -> Option<&'static str> {
+        let extension = self.content_url.as_ref().and_then(video_extension)?;
+        Some(if extension == "m3u8" {
+            "mp4"
+        } else {
+            extension
+        })
+    }
+
+    /// Downloads the video file to the path with ffmpeg, which must be installed.
+    ///
+    /// Only videos with an HTTP or HTTPS [`content_url`](RecipeVideo::content_url) can be
+    /// downloaded. The streams are copied as they are, so the extension of the path should be
+    /// the [`file_extension`](RecipeVideo::file_extension) of the video. The download stops
+    /// after [`MAX_DOWNLOAD_DURATION`], and the partial file is removed when it fails.
+    pub async fn download(&self, path: &Path) -> Result<PathBuf> {
+        let url = self
+            .content_url
+            .as_ref()
+            .filter(|url| is_http(url))
+            .ok_or(Error::NoVideoFile)?;
+
+        let child = Command::new("ffmpeg")
+            .args(["-y", "-loglevel", "error"])
+            // Playlists could otherwise point ffmpeg at local files or other protocols.
+            .args(["-protocol_whitelist", "http,https,tls,tcp,crypto"])
+            .args(["-i", url.as_str(), "-c", "copy"])
+            .arg(path)
+            .stdin(Stdio::null())
+            .stdout(Stdio::null())
+            .stderr(Stdio::piped())
+            .kill_on_drop(true)
+            .spawn()?;
+
+        let message = match timeout(MAX_DOWNLOAD_DURATION, child.wait_with_output()).await {
+            Ok(output) => {
+                let output = output?;
+                if output.status.success() {
+                    return Ok(path.to_path_buf());
+                }
+                String::from_utf8_lossy(&output.stderr).trim().to_string()
+            }
+            Err(_) => format!(
+                "the download took longer than {} seconds",
+                MAX_DOWNLOAD_DURATION.as_secs()
+            ),
+        };
+
+        let _ = std::fs::remove_file(path);
+        Err(Error::Ffmpeg { message })
+    }
+}
+
+/// Recognizes the YouTube and Vimeo videos from the URL of their page or of their player.
+fn video_host(url: &Url) -> Option<VideoHost> {
+    let host = url
+        .host_str()?
+        .trim_start_matches("www.")
+        .trim_start_matches("m.");
+    let mut segments = url.path_segments()?.filter(|segment| !segment.is_empty());
+
+    let id = match host {
+        "youtu.be" => segments.next().map(str::to_string),
+        "youtube.com" | "youtube-nocookie.com" => match segments.next() {
+            Some("watch") => url
+                .query_pairs()
+                .find(|(key, _)| key == "v")
+                .map(|(_, id)| id.into_owned()),
+            Some("embed" | "shorts" | "live" | "v") => segments.next().map(str::to_string),
+            _ => None,
+        },
+        "vimeo.com" | "player.vimeo.com" => {
+            let id = segments.find(|segment| segment.chars().all(|c| c.is_ascii_digit()))?;
+            return Some(VideoHost::Vimeo { id: id.to_string() });
+        }
+        _ => None,
+    }?;
+
+    let is_valid = !id.is_empty()
+        && id
+            .chars()
+            .all(|c| c.is_ascii_alphanumeric() || c == '-' || c == '_');
+    is_valid.then_some(VideoHost::YouTube { id })
+}
+
+fn is_video_file(url: &Url) -> bool {
+    is_http(url) && video_extension(url).is_some()
+}
+
+fn is_http(url: &Url) -> bool {
+    matches!(url.scheme(), "http" | "https")
+}
+
+/// The extension of the video file of the URL, in lowercase.
+fn video_extension(url: &Url) -> Option<&'static str> {
+    let (_, extension) = url.path().rsplit_once('.')?;
+    VIDEO_EXTENSIONS
+        .into_iter()
+        .find(|known| known.eq_ignore_ascii_case(extension))
+}
@@ -3,7 +3,7 @@ use std::{collections::HashMap, fmt::Formatter, sync::OnceLock};
 
 use crate::{Error, Result};
 
-#[derive(Clone, Copy, Debug, Eq, Hash, PartialEq)]
+#[derive(Clone, Debug, Eq, Hash, PartialEq)]
 pub enum Website {
     O101cookbooksDotCom,
     O15gramsDotCom,
@@ -406,6 +406,14 @@ pub enum Website {
     YumeliseDotFr,
     ZeitDotDe,
     ZenbellyDotCom,
+
+    /// A website added at runtime from a [`Registry`](crate::Registry), identified by its
+    /// domain.
+    Custom(String),
+
+    /// A website that is not supported, identified by its domain. Its recipe is read from
+    /// the structured data of the page alone.
+    Generic(String),
 }
 
 impl Website {
@@ -421,14 +429,38 @@ impl Website {
         };
 
         match websites_map_to_website().get(domain) {
-            Some(&website) => Ok(website),
+            Some(website) => Ok(website.clone()),
             None => Err(Error::UnknownWebsite),
         }
     }
+
+    /// Like [`Website::from`], but returns a [`Website::Generic`] for the unsupported
+    /// websites instead of an error.
+    pub fn from_any(url: &str) -> Result<Self> {
+        match Self::from(url) {
+            Err(Error::UnknownWebsite) => {
+                let url = Url::parse(url).map_err(|_| Error::UnknownWebsite)?;
+                match url.domain() {
+                    Some(domain) => Ok(Website::Generic(domain.to_string())),
+                    None => Err(Error::NoHost),
+                }
+            }
+            res => res,
+        }
+    }
+
+    /// Whether the website is not one of the supported websites.
+    pub fn is_generic(&self) -> bool {
+        matches!(self, Website::Generic(_))
+    }
 }
 
 impl std::fmt::Display for Website {
     fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        if let Website::Custom(domain) | Website::Generic(domain) = self {
+            return write!(f, "{domain}");
+        }
+
         match websites_map_to_domain().get_key_value(self) {
             Some((_, url)) => write!(f, "{url}"),
             None => write!(f, "URL not found"),
@@ -436,7 +468,7 @@ impl std::fmt::Display for Website {
     }
 }
 
-fn websites_map_to_domain() -> &'static HashMap<Website, String> {
+pub(crate) fn websites_map_to_domain() -> &'static HashMap<Website, String> {
     static INSTANCE: OnceLock<HashMap<Website, String>> = OnceLock::new();
 
     INSTANCE.get_or_init(|| {
@@ -1553,15 +1585,15 @@ fn websites_map_to_domain() -> &'static HashMap<Website, String> {
     })
 }
 
-fn websites_map_to_website() -> &'static HashMap<String, Website> {
+pub(crate) fn websites_map_to_website() -> &'static HashMap<String, Website> {
     static INSTANCE: OnceLock<HashMap<String, Website>> = OnceLock::new();
 
     INSTANCE.get_or_init(|| {
         let mut map: HashMap<String, Website> = HashMap::new();
         websites_map_to_domain()
             .iter()
-            .for_each(|(&website, domain)| {
-                let _ = map.insert(domain.clone(), website);
+            .for_each(|(website, domain)| {
+                let _ = map.insert(domain.clone(), website.clone());
             });
         map
     })
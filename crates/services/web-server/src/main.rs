@@ -29,6 +29,7 @@ async fn main() -> Result<()> {
     );
 
     let state = AppState::new().await.unwrap();
+    lib_core::scraper::init_scraper(&state.mm).await?;
     axum::serve(listener, routes_all(state).await.unwrap())
         .with_graceful_shutdown(shutdown_signal())
         .await